@@ -0,0 +1,191 @@
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::CardData;
+use crate::game_state::Zone;
+use crate::mana::ColorSet;
+
+// How a card's faces are laid out, named as MTGJSON and Scryfall do
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CardLayout {
+    #[default]
+    Normal,
+    Split,
+    Aftermath,
+    Flip,
+    Transform,
+    ModalDfc,
+    Adventure,
+    Meld,
+    Prototype,
+    // sagas, levelers, tokens... which only have one face
+    Other(String),
+}
+
+static LAYOUT_NAMES: [(CardLayout, &str); 9] = [
+    (CardLayout::Normal, "normal"),
+    (CardLayout::Split, "split"),
+    (CardLayout::Aftermath, "aftermath"),
+    (CardLayout::Flip, "flip"),
+    (CardLayout::Transform, "transform"),
+    (CardLayout::ModalDfc, "modal_dfc"),
+    (CardLayout::Adventure, "adventure"),
+    (CardLayout::Meld, "meld"),
+    (CardLayout::Prototype, "prototype"),
+];
+
+impl Display for CardLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CardLayout::Other(name) => f.write_str(name),
+            layout => f.write_str(
+                LAYOUT_NAMES
+                    .iter()
+                    .find(|(known, _)| known == layout)
+                    .map_or("", |(_, name)| name),
+            ),
+        }
+    }
+}
+
+impl FromStr for CardLayout {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("Empty card layout".to_owned());
+        }
+        Ok(LAYOUT_NAMES
+            .iter()
+            .find(|(_, name)| *name == s)
+            .map_or_else(
+                || CardLayout::Other(s.to_owned()),
+                |(layout, _)| layout.clone(),
+            ))
+    }
+}
+
+impl CardLayout {
+    // Cards with a face on each side @CR 712.1
+    pub fn is_double_faced(&self) -> bool {
+        matches!(
+            self,
+            CardLayout::Transform | CardLayout::ModalDfc | CardLayout::Meld
+        )
+    }
+}
+
+// A physical card and each of its faces, the front face first. A meld card
+// has its own front face and the back face it shares with its partner.
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct Card {
+    pub name: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub layout: CardLayout,
+    // never empty, see new()
+    #[serde(rename = "card_faces")]
+    faces: Vec<CardData>,
+}
+
+impl Card {
+    pub fn new(name: String, layout: CardLayout, faces: Vec<CardData>) -> Result<Self, String> {
+        if faces.is_empty() {
+            return Err(format!("{} has no faces", name));
+        }
+        Ok(Self {
+            name,
+            layout,
+            faces,
+        })
+    }
+
+    pub fn faces(&self) -> &[CardData] {
+        &self.faces
+    }
+
+    pub fn front_face(&self) -> &CardData {
+        &self.faces[0]
+    }
+
+    // Only double-faced cards have a back face, the second half of a split
+    // card or an adventure is on the front
+    pub fn back_face(&self) -> Option<&CardData> {
+        if self.layout.is_double_faced() {
+            self.faces.get(1)
+        } else {
+            None
+        }
+    }
+
+    // The faces whose characteristics the card has in `zone`. `face` is the
+    // one that was cast, or that's up on the battlefield. Outside of those the
+    // card only has its front face's characteristics, except split cards which
+    // have both halves' @CR 709.4 @CR 710.1 @CR 712.8a @CR 715.4
+    pub fn characteristics(&self, zone: Zone, face: usize) -> Vec<&CardData> {
+        let chosen = self.faces.get(face).unwrap_or(self.front_face());
+        match (&self.layout, zone) {
+            (CardLayout::Split | CardLayout::Aftermath, Zone::Stack) => vec![chosen],
+            (CardLayout::Split | CardLayout::Aftermath, _) => self.faces.iter().collect(),
+            (layout, Zone::Stack | Zone::Battlefield) if layout.is_double_faced() => vec![chosen],
+            (CardLayout::Flip, Zone::Battlefield) => vec![chosen],
+            (CardLayout::Adventure, Zone::Stack) => vec![chosen],
+            _ => vec![self.front_face()],
+        }
+    }
+
+    // Every face counts towards color identity @CR 903.4d
+    pub fn color_identity(&self) -> ColorSet {
+        self.faces
+            .iter()
+            .map(CardData::color_identity)
+            .fold(ColorSet::new(), |identity, face| identity | face)
+    }
+
+    // Puts MTGJSON's separate face entries back together. Each card is its
+    // side "a" entry (or an entry without a side) plus the other faces it
+    // points to that aren't a front face themselves.
+    pub fn from_mtgjson(entries: impl IntoIterator<Item = MtgJsonCard>) -> Vec<Card> {
+        let entries = entries.into_iter().collect::<Vec<_>>();
+        let by_uuid = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (entry.uuid.as_str(), index))
+            .collect::<HashMap<_, _>>();
+        let is_front = |entry: &MtgJsonCard| entry.side.as_deref().is_none_or(|side| side == "a");
+        let mut cards = vec![];
+        for entry in entries.iter().filter(|entry| is_front(entry)) {
+            let mut others = entry
+                .other_face_ids
+                .iter()
+                .flat_map(|id| by_uuid.get(id.as_str()))
+                .map(|index| &entries[*index])
+                .filter(|other| !is_front(other))
+                .collect::<Vec<_>>();
+            others.sort_by(|a, b| a.side.cmp(&b.side));
+            let mut faces = vec![entry.face.clone()];
+            faces.extend(others.iter().map(|other| other.face.clone()));
+            // the entry itself is always the front face
+            cards.push(Card {
+                name: entry.name.clone(),
+                layout: entry.layout.clone(),
+                faces,
+            });
+        }
+        cards
+    }
+}
+
+// A single MTGJSON card entry, which only has one face of its card
+#[derive(Debug, Clone)]
+pub struct MtgJsonCard {
+    pub uuid: String,
+    // the name of the whole card, e.g. "Fire // Ice"
+    pub name: String,
+    pub layout: CardLayout,
+    pub side: Option<String>,
+    pub other_face_ids: Vec<String>,
+    pub face: CardData,
+}
@@ -1,219 +1,544 @@
-use serde::{de, Deserialize, Serialize};
+use serde::{de, ser, Deserialize, Serialize};
 use std::str::FromStr;
-use strum::IntoEnumIterator;
 
-use crate::card_data::types::{
-    ArtifactSubtype, BattleType, CreatureType, EnchantmentType, LandType, PlaneswalkerType,
-    SpellType,
-};
+use crate::card_data::types::{MaybePlaceholderNumber, StatExpression, SubtypeRegistry};
 
+use super::type_line::{type_data_with_subtypes, TypeLine};
 use super::{
-    ArtifactData, BattleData, CardType, CardTypeData, CreatureData, EnchantmentData, InstantData,
-    LandData, PlaneswalkerData, SorceryData, TribalData,
+    Card, CardData, CardLayout, CardSupertype, CardType, CardTypeData, Keyword, MtgJsonCard,
 };
+use crate::mana::{ColorSet, ManaCost};
 
-impl From<FlatCardTypeData> for Vec<CardTypeData> {
-    fn from(value: FlatCardTypeData) -> Self {
-        CardType::iter()
-            // TODO: figure out how to avoid cloning here
-            .flat_map(|variant| match variant {
-                CardType::Land => Some(CardTypeData::Land(value.clone().land_data?)),
-                CardType::Creature => Some(CardTypeData::Creature(value.clone().creature_data?)),
-                CardType::Artifact => Some(CardTypeData::Artifact(value.clone().artifact_data?)),
-                CardType::Enchantment => {
-                    Some(CardTypeData::Enchantment(value.clone().enchantment_data?))
-                }
-                CardType::Tribal => Some(CardTypeData::Tribal(value.clone().tribal_data?)),
-                CardType::Planeswalker => {
-                    Some(CardTypeData::Planeswalker(value.clone().planeswalker_data?))
-                }
-                CardType::Instant => None,
-                CardType::Sorcery => None,
-                CardType::Battle => None,
-            })
-            .collect::<Vec<_>>()
+// What Kindred is called when card data is serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum_macros::Display)]
+pub enum KindredName {
+    #[default]
+    Kindred,
+    Tribal,
+}
+
+// Serializes `data` with Kindred written under `kindred_name`, e.g. to write
+// data for tools that predate the rename. Serializing CardData as usual writes
+// Kindred.
+pub fn serialize_with_kindred_name<S>(
+    data: &CardData,
+    kindred_name: KindredName,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use ser::SerializeMap;
+    let flat = FlatTypeData::new(&data.type_data, kindred_name);
+    let colors = [
+        ("colors", &data.colors),
+        ("colorIndicator", &data.color_indicator),
+        ("colorIdentity", &data.color_identity),
+    ];
+    let len = 3
+        + usize::from(data.text.is_some())
+        + usize::from(data.flavor_text.is_some())
+        + usize::from(!data.keywords.is_empty())
+        + colors.iter().filter(|(_, colors)| colors.is_some()).count()
+        + flat.len();
+    let mut map = serializer.serialize_map(Some(len))?;
+    map.serialize_entry("name", &data.name)?;
+    map.serialize_entry(
+        "manaCost",
+        &data.mana_cost.as_ref().map(|cost| cost.to_string()),
+    )?;
+    if data.text.is_some() {
+        map.serialize_entry("text", &data.text)?;
+    }
+    if data.flavor_text.is_some() {
+        map.serialize_entry("flavorText", &data.flavor_text)?;
+    }
+    if !data.keywords.is_empty() {
+        let keywords = data
+            .keywords
+            .iter()
+            .map(|k| k.to_string())
+            .collect::<Vec<_>>();
+        map.serialize_entry("keywords", &keywords)?;
+    }
+    for (key, colors) in colors {
+        if let Some(colors) = colors {
+            map.serialize_entry(key, &Some(color_letters(colors)))?;
+        }
     }
+    let supertypes = data
+        .supertypes
+        .iter()
+        .map(|supertype| supertype.to_string())
+        .collect::<Vec<_>>();
+    map.serialize_entry("supertypes", &supertypes)?;
+    flat.serialize_entries(&mut map)?;
+    map.end()
+}
+
+// For #[serde(serialize_with)] on fields that hold card data
+pub fn serialize_as_tribal<S>(data: &CardData, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serialize_with_kindred_name(data, KindredName::Tribal, serializer)
 }
 
-#[derive(Serialize, Clone, Default)]
-pub struct FlatCardTypeData {
+// A card's type data in the flat MTGJSON layout: one list of types, one list
+// of subtypes, and the other fields of each type next to them
+struct FlatTypeData {
     types: Vec<String>,
     subtypes: Vec<String>,
-    #[serde(flatten)]
-    land_data: Option<LandData>,
-    #[serde(flatten)]
-    creature_data: Option<CreatureData>,
-    #[serde(flatten)]
-    artifact_data: Option<ArtifactData>,
-    #[serde(flatten)]
-    enchantment_data: Option<EnchantmentData>,
-    #[serde(flatten)]
-    tribal_data: Option<TribalData>,
-    #[serde(flatten)]
-    planeswalker_data: Option<PlaneswalkerData>,
-    #[serde(flatten)]
-    instant_data: Option<InstantData>,
-    #[serde(flatten)]
-    sorcery_data: Option<SorceryData>,
-    #[serde(flatten)]
-    battle_data: Option<BattleData>,
-}
-
-impl From<Vec<CardTypeData>> for FlatCardTypeData {
-    fn from(val: Vec<CardTypeData>) -> Self {
-        let mut output = Self::default();
-        macro_rules! extract_type_data {
-            ($data:ident.$subtypes:ident) => {{
-                output.subtypes.extend(
-                    $data
-                        .$subtypes
-                        .iter()
-                        .map(|lt| lt.to_string())
-                        .collect::<Vec<_>>(),
-                )
-            }};
-        }
-        for type_data in val {
-            let name = CardType::from(&type_data).to_string();
-            output.types.push(name);
-            match type_data {
-                CardTypeData::Land(data) => {
-                    extract_type_data! { data.land_types }
-                }
-                CardTypeData::Creature(data) => {
-                    extract_type_data! { data.creature_types }
-                }
-                CardTypeData::Artifact(data) => {
-                    extract_type_data! { data.artifact_types }
-                }
-                CardTypeData::Enchantment(data) => {
-                    extract_type_data! { data.enchantment_types }
-                }
-                CardTypeData::Tribal(data) => {
-                    extract_type_data! { data.tribal_types }
-                }
-                CardTypeData::Planeswalker(data) => {
-                    extract_type_data! { data.planeswalker_types }
-                }
-                CardTypeData::Instant(data) => {
-                    extract_type_data! { data.spell_types }
+    fields: Vec<(&'static str, String)>,
+}
+
+impl FlatTypeData {
+    fn new(type_data: &[CardTypeData], kindred_name: KindredName) -> Self {
+        let mut flat = Self {
+            types: vec![],
+            subtypes: vec![],
+            fields: vec![],
+        };
+        for data in type_data {
+            flat.types.push(match CardType::from(data) {
+                CardType::Kindred => kindred_name.to_string(),
+                card_type => card_type.to_string(),
+            });
+            flat.subtypes.extend(data.subtypes());
+            match data {
+                CardTypeData::Creature(creature) => {
+                    flat.fields.push(("power", creature.power.to_string()));
+                    flat.fields
+                        .push(("toughness", creature.toughness.to_string()));
                 }
-                CardTypeData::Sorcery(data) => {
-                    extract_type_data! { data.spell_types }
+                CardTypeData::Planeswalker(planeswalker) => {
+                    flat.fields
+                        .push(("loyalty", planeswalker.loyalty.to_string()));
                 }
-                CardTypeData::Battle(data) => {
-                    extract_type_data! { data.battle_types }
+                CardTypeData::Battle(battle) => {
+                    flat.fields.push(("defense", battle.defense.to_string()));
                 }
+                _ => {}
+            }
+        }
+        flat
+    }
+
+    fn len(&self) -> usize {
+        2 + self.fields.len()
+    }
+
+    fn serialize_entries<M: ser::SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+        map.serialize_entry("types", &self.types)?;
+        map.serialize_entry("subtypes", &self.subtypes)?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        Ok(())
+    }
+}
+
+// The flat fields of a card as they are read, before we know which of its
+// types each one belongs to
+#[derive(Default)]
+struct FlatFields {
+    types: Option<Vec<String>>,
+    subtypes: Option<Vec<String>>,
+    power: Option<StatExpression>,
+    toughness: Option<StatExpression>,
+    loyalty: Option<StatExpression>,
+    defense: Option<MaybePlaceholderNumber>,
+}
+
+fn next_number<'de, A: de::MapAccess<'de>, T: FromStr<Err: std::fmt::Display>>(
+    map: &mut A,
+) -> Result<T, A::Error> {
+    T::from_str(&map.next_value::<String>()?).map_err(de::Error::custom)
+}
+
+impl FlatFields {
+    // Reads the value of `key` if it's one of ours, returns false otherwise so
+    // the caller can deal with it
+    fn read<'de, A: de::MapAccess<'de>>(
+        &mut self,
+        key: &str,
+        map: &mut A,
+    ) -> Result<bool, A::Error> {
+        match key {
+            "types" => self.types = Some(map.next_value()?),
+            "subtypes" => self.subtypes = Some(map.next_value()?),
+            "power" => self.power = Some(next_number(map)?),
+            "toughness" => self.toughness = Some(next_number(map)?),
+            "loyalty" => self.loyalty = Some(next_number(map)?),
+            "defense" => self.defense = Some(next_number(map)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn finish<E: de::Error>(mut self) -> Result<Vec<CardTypeData>, E> {
+        let types = self
+            .types
+            .take()
+            .ok_or(E::missing_field("types"))?
+            .iter()
+            .map(|name| CardType::from_str(name).map_err(|_| E::custom("invalid type value")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let subtypes = self.subtypes.take().ok_or(E::missing_field("subtypes"))?;
+        let mut type_data = type_data_with_subtypes(&SubtypeRegistry::current(), &types, &subtypes)
+            .map_err(E::custom)?;
+        self.fill_in(&mut type_data)?;
+        Ok(type_data)
+    }
+
+    // Hands the fields that aren't subtypes to the types they belong to
+    fn fill_in<E: de::Error>(&mut self, type_data: &mut [CardTypeData]) -> Result<(), E> {
+        macro_rules! field {
+            ($name:ident) => {
+                self.$name
+                    .take()
+                    .ok_or(E::missing_field(stringify!($name)))?
             };
         }
-        output
+        for data in type_data.iter_mut() {
+            match data {
+                CardTypeData::Creature(creature) => {
+                    creature.power = field!(power);
+                    creature.toughness = field!(toughness);
+                }
+                CardTypeData::Planeswalker(planeswalker) => planeswalker.loyalty = field!(loyalty),
+                CardTypeData::Battle(battle) => battle.defense = field!(defense),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+// Colors are lists of letters, e.g. ["W", "U"]
+fn color_letters(colors: &ColorSet) -> Vec<String> {
+    colors.iter().map(|color| color.to_string()).collect()
+}
+
+fn parse_colors<E: de::Error>(letters: Option<Vec<String>>) -> Result<Option<ColorSet>, E> {
+    letters
+        .map(|letters| ColorSet::from_str(&letters.concat()))
+        .transpose()
+        .map_err(E::custom)
+}
+
+// The fields of one face as they are read. Everything is kept as printed
+// until the whole map has been read, since a multi-face card's own type line
+// and mana cost ("{1}{R} // {1}{U}") aren't those of any face.
+#[derive(Default)]
+struct CardDataFields {
+    name: Option<String>,
+    face_name: Option<String>,
+    mana_cost: Option<String>,
+    text: Option<String>,
+    flavor_text: Option<String>,
+    keywords: Vec<String>,
+    colors: Option<Vec<String>>,
+    color_indicator: Option<Vec<String>>,
+    color_identity: Option<Vec<String>>,
+    supertypes: Option<Vec<String>>,
+    type_line: Option<String>,
+    fields: FlatFields,
+}
+
+impl CardDataFields {
+    // Takes the keys of both MTGJSON and Scryfall
+    fn read<'de, A: de::MapAccess<'de>>(
+        &mut self,
+        key: &str,
+        map: &mut A,
+    ) -> Result<bool, A::Error> {
+        match key {
+            "name" => self.name = Some(map.next_value()?),
+            "faceName" => self.face_name = Some(map.next_value()?),
+            "manaCost" | "mana_cost" => self.mana_cost = map.next_value()?,
+            "text" | "oracle_text" => self.text = map.next_value()?,
+            "flavorText" | "flavor_text" => self.flavor_text = map.next_value()?,
+            "keywords" => self.keywords = map.next_value()?,
+            "colors" => self.colors = map.next_value()?,
+            "colorIndicator" | "color_indicator" => self.color_indicator = map.next_value()?,
+            "colorIdentity" | "color_identity" => self.color_identity = map.next_value()?,
+            "supertypes" => self.supertypes = Some(map.next_value()?),
+            // Scryfall only has the printed type line
+            "type_line" => self.type_line = Some(map.next_value()?),
+            key => return self.fields.read(key, map),
+        }
+        Ok(true)
+    }
+
+    fn finish<E: de::Error>(mut self) -> Result<CardData, E> {
+        let mut supertypes = self
+            .supertypes
+            .map(|supertypes| {
+                supertypes
+                    .iter()
+                    .map(|supertype| CardSupertype::from_str(supertype))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(E::custom)
+            })
+            .transpose()?;
+        let type_data = match self.type_line {
+            Some(line) if self.fields.types.is_none() => {
+                let line = TypeLine::from_str(&line).map_err(E::custom)?;
+                supertypes = supertypes.or(Some(line.supertypes));
+                let mut type_data = line.type_data;
+                self.fields.fill_in(&mut type_data)?;
+                type_data
+            }
+            _ => self.fields.finish()?,
+        };
+        Ok(CardData {
+            // MTGJSON names every face after the whole card
+            name: self
+                .face_name
+                .or(self.name)
+                .ok_or(E::missing_field("name"))?,
+            // Scryfall leaves the cost empty instead of leaving it out
+            mana_cost: self
+                .mana_cost
+                .filter(|cost| !cost.is_empty())
+                .map(|cost| ManaCost::from_str(&cost))
+                .transpose()
+                .map_err(E::custom)?,
+            keywords: self
+                .keywords
+                .iter()
+                .map(|name| Keyword::from_name_and_text(name, self.text.as_deref()))
+                .collect(),
+            text: self.text,
+            flavor_text: self.flavor_text,
+            colors: parse_colors(self.colors)?,
+            color_indicator: parse_colors(self.color_indicator)?,
+            color_identity: parse_colors(self.color_identity)?,
+            supertypes: supertypes.ok_or(E::missing_field("supertypes"))?,
+            type_data,
+        })
     }
 }
 
-fn to_option<T, A, E, F: FnOnce(A) -> Result<T, E>>(f: F) -> impl FnOnce(A) -> Option<T> {
-    |a: A| {
-        let x = f(a);
-        x.ok()
+// Always a map of known length, so formats that need the length up front
+// like bincode can write it. Optional fields are left out when they're empty,
+// and the ones that can be null are written as options so every format reads
+// them back the same way.
+impl Serialize for CardData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_with_kindred_name(self, KindredName::Kindred, serializer)
     }
 }
 
-impl<'de> Deserialize<'de> for FlatCardTypeData {
+// CardData can't derive Deserialize because serde buffers flattened fields,
+// which formats like RON can't read back. The map is streamed through once:
+// the fields CardData knows about are read directly and the rest go to
+// FlatFields. Every value is read as the type it was written as, so this works
+// with RON, bincode and MessagePack as well as JSON. Only keys we don't know
+// are skipped, which needs a self-describing format, and our own output has none.
+impl<'de> Deserialize<'de> for CardData {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        use de::Error;
-        use CardType::*;
-
-        // TODO: generalize with visitor?
-        let json: serde_json::value::Value = serde_json::value::Value::deserialize(deserializer)?;
-        let type_values = json
-            .get("types")
-            .and_then(|t| t.as_array())
-            .ok_or(Error::custom("types array not found"))?;
-
-        let mut output = Self::default();
-        let subtypes = json
-            .get("subtypes")
-            .and_then(|v| v.as_array())
-            .ok_or(Error::custom("subtypes array not found"))?
-            .iter()
-            .map(|s| s.as_str().unwrap().to_owned())
-            .collect::<Vec<_>>();
-        let mut used_subtypes: Vec<&str> = vec![];
-        // Macro for repetitive code to extract subtypes from type data
-        macro_rules! de_type_data {
-            ($datatype:ty:$getter:expr => $name:ident.$subtypes:ident) => {{
-                let mut data: $datatype = serde_json::from_value(json.clone()).map_err(|e| {
-                    Error::custom(format!(
-                        "failed to deserialize {}: {}",
-                        stringify!($datatype),
-                        e,
-                    ))
-                })?;
-                data.$subtypes = subtypes
-                    .iter()
-                    .flat_map(|i| {
-                        if let Some(subtype) = $getter(i) {
-                            used_subtypes.push(i);
-                            Some(subtype)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect::<Vec<_>>();
-                output.$name = Some(data);
-            }};
+        deserializer.deserialize_map(CardDataVisitor)
+    }
+}
+
+struct CardDataVisitor;
+
+impl<'de> de::Visitor<'de> for CardDataVisitor {
+    type Value = CardData;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of card data")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut fields = CardDataFields::default();
+        while let Some(key) = map.next_key::<String>()? {
+            if !fields.read(&key, &mut map)? {
+                map.next_value::<de::IgnoredAny>()?;
+            }
         }
-        for type_name in type_values {
-            let card_type = type_name
-                .as_str()
-                .and_then(|v| CardType::from_str(v).ok())
-                .ok_or(Error::custom("invalid type value"))?;
-            output.types.push(card_type.to_string());
-            match card_type {
-                Land => {
-                    de_type_data! { LandData: to_option(LandType::from_str) => land_data.land_types }
-                }
-                Creature => {
-                    de_type_data! { CreatureData: CreatureType::new_validated => creature_data.creature_types }
-                }
-                Artifact => {
-                    de_type_data! { ArtifactData: to_option(ArtifactSubtype::from_str) => artifact_data.artifact_types }
-                }
-                Enchantment => {
-                    de_type_data! { EnchantmentData: to_option(EnchantmentType::from_str) => enchantment_data.enchantment_types }
-                }
-                Planeswalker => {
-                    de_type_data! { PlaneswalkerData: PlaneswalkerType::new_validated => planeswalker_data.planeswalker_types }
-                }
-                Tribal => {
-                    de_type_data! { TribalData: CreatureType::new_validated => tribal_data.tribal_types }
-                }
-                Instant => {
-                    de_type_data! { InstantData: to_option(SpellType::from_str) => instant_data.spell_types }
-                }
-                Sorcery => {
-                    de_type_data! { SorceryData: to_option(SpellType::from_str) => sorcery_data.spell_types }
+        fields.finish()
+    }
+}
+
+// A struct field or map key, RON only reads struct fields as identifiers
+struct FieldName(String);
+
+impl<'de> Deserialize<'de> for FieldName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct FieldNameVisitor;
+        impl de::Visitor<'_> for FieldNameVisitor {
+            type Value = FieldName;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a field name")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(FieldName(v.to_owned()))
+            }
+        }
+        deserializer.deserialize_identifier(FieldNameVisitor)
+    }
+}
+
+// Our own layout and Scryfall's: a card with its faces in "card_faces", or
+// with the only face's fields next to the card's
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // a struct for formats like RON, which write Card as one
+        deserializer.deserialize_struct("Card", &["name", "layout", "card_faces"], CardVisitor)
+    }
+}
+
+struct CardVisitor;
+
+impl<'de> de::Visitor<'de> for CardVisitor {
+    type Value = Card;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a card with one or more faces")
+    }
+
+    // How formats without field names, like bincode, write our own layout
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        use de::Error;
+        let name: String = seq.next_element()?.ok_or(Error::invalid_length(0, &self))?;
+        let layout: String = seq.next_element()?.ok_or(Error::invalid_length(1, &self))?;
+        let faces = seq.next_element()?.ok_or(Error::invalid_length(2, &self))?;
+        let layout = CardLayout::from_str(&layout).map_err(Error::custom)?;
+        Card::new(name, layout, faces).map_err(Error::custom)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        use de::Error;
+        let mut layout = None;
+        let mut faces: Option<Vec<CardData>> = None;
+        let mut fields = CardDataFields::default();
+        while let Some(FieldName(key)) = map.next_key()? {
+            match key.as_str() {
+                "layout" => layout = Some(map.next_value::<String>()?),
+                "card_faces" => faces = Some(map.next_value()?),
+                key => {
+                    if !fields.read(key, &mut map)? {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
                 }
-                Battle => {
-                    de_type_data! { BattleData: to_option(BattleType::from_str) => battle_data.battle_types }
+            }
+        }
+        let name = fields.name.clone().ok_or(Error::missing_field("name"))?;
+        let faces = match faces {
+            Some(mut faces) if !faces.is_empty() => {
+                give_keywords_to_faces(&fields.keywords, &mut faces);
+                faces
+            }
+            _ => vec![fields.finish()?],
+        };
+        let layout = layout
+            .map(|layout| CardLayout::from_str(&layout))
+            .transpose()
+            .map_err(Error::custom)?
+            .unwrap_or_default();
+        Card::new(name, layout, faces).map_err(Error::custom)
+    }
+}
+
+// Scryfall only lists keywords for the whole card, never on its faces. Each
+// goes to the faces whose rules text has it, or the front face if none does.
+fn give_keywords_to_faces(names: &[String], faces: &mut [CardData]) {
+    for name in names {
+        let lower = name.to_lowercase();
+        let has_it = |face: &CardData| {
+            face.text
+                .as_deref()
+                .is_some_and(|text| text.to_lowercase().contains(&lower))
+        };
+        let none_has_it = !faces.iter().any(has_it);
+        for (index, face) in faces.iter_mut().enumerate() {
+            if has_it(face) || (none_has_it && index == 0) {
+                let keyword = Keyword::from_name_and_text(name, face.text.as_deref());
+                if !face.keywords.contains(&keyword) {
+                    face.keywords.push(keyword);
                 }
             }
         }
+    }
+}
 
-        let unused_subtypes = subtypes
-            .iter()
-            .filter(|st| !used_subtypes.contains(&st.as_str()))
-            .collect::<Vec<_>>();
+// One entry of an MTGJSON card list, which has a separate entry per face
+impl<'de> Deserialize<'de> for MtgJsonCard {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(MtgJsonCardVisitor)
+    }
+}
 
-        if !unused_subtypes.is_empty() {
-            return Err(Error::custom(format!(
-                "Invalid subtypes: {:?}",
-                unused_subtypes
-            )));
-        }
+struct MtgJsonCardVisitor;
+
+impl<'de> de::Visitor<'de> for MtgJsonCardVisitor {
+    type Value = MtgJsonCard;
 
-        Ok(output)
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an MTGJSON card")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        use de::Error;
+        let mut uuid = None;
+        let mut layout = None;
+        let mut side = None;
+        let mut other_face_ids = vec![];
+        let mut fields = CardDataFields::default();
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "uuid" => uuid = Some(map.next_value()?),
+                "layout" => layout = Some(map.next_value::<String>()?),
+                "side" => side = map.next_value()?,
+                "otherFaceIds" => other_face_ids = map.next_value()?,
+                key => {
+                    if !fields.read(key, &mut map)? {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+            }
+        }
+        Ok(MtgJsonCard {
+            uuid: uuid.ok_or(Error::missing_field("uuid"))?,
+            name: fields.name.clone().ok_or(Error::missing_field("name"))?,
+            layout: layout
+                .map(|layout| CardLayout::from_str(&layout))
+                .transpose()
+                .map_err(Error::custom)?
+                .unwrap_or_default(),
+            side,
+            other_face_ids,
+            face: fields.finish()?,
+        })
     }
 }
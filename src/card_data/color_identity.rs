@@ -0,0 +1,88 @@
+use crate::mana::{ColorSet, ManaCost, ManaSymbol};
+use std::str::FromStr;
+
+use super::CardData;
+
+// Colors of every mana symbol in a piece of rules text.
+// Reminder text isn't rules text, so anything in parentheses is skipped @CR 207.2a
+pub fn rules_text_colors(text: &str) -> ColorSet {
+    let mut colors = ColorSet::new();
+    let mut depth = 0usize;
+    let mut symbol: Option<String> = None;
+    for ch in text.chars() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth > 0 => {}
+            '{' => symbol = Some(String::from(ch)),
+            '}' => {
+                if let Some(mut s) = symbol.take() {
+                    s.push(ch);
+                    // non-mana symbols like {T} don't have a color
+                    if let Ok(parsed) = ManaSymbol::from_str(&s) {
+                        colors = colors | parsed.get_colors();
+                    }
+                }
+            }
+            _ => {
+                if let Some(s) = symbol.as_mut() {
+                    s.push(ch)
+                }
+            }
+        }
+    }
+    colors
+}
+
+// The color identity of a single face @CR 903.4
+pub fn face_color_identity(
+    mana_cost: Option<&ManaCost>,
+    rules_text: Option<&str>,
+    color_indicator: Option<ColorSet>,
+) -> ColorSet {
+    mana_cost.map(ManaCost::get_colors).unwrap_or_default()
+        | rules_text.map(rules_text_colors).unwrap_or_default()
+        | color_indicator.unwrap_or_default()
+}
+
+impl CardData {
+    pub fn color_identity(&self) -> ColorSet {
+        // the data's own identity can include faces and parts we don't see here
+        face_color_identity(
+            self.mana_cost.as_ref(),
+            self.text.as_deref(),
+            self.color_indicator,
+        ) | self.color_identity.unwrap_or_default()
+    }
+}
+
+#[test]
+fn computes_color_identity() {
+    // Figure of Destiny
+    assert_eq!(
+        face_color_identity(
+            ManaCost::from_str("{R/W}").ok().as_ref(),
+            Some("{R/W}: Figure of Destiny becomes a Kithkin Spirit with base power and toughness 4/4."),
+            None,
+        )
+        .to_string(),
+        "WR"
+    );
+    // Birds of Paradise
+    assert_eq!(
+        rules_text_colors("Flying\n{T}: Add one mana of any color.").to_string(),
+        "C"
+    );
+    // Tithe Taker's extort reminder text doesn't count
+    assert_eq!(
+        rules_text_colors(
+            "Afterlife 1 (When this creature dies, create a 1/1 white and black Spirit creature token with flying.)\nExtort (Whenever you cast a spell, you may pay {W/B}. If you do, each opponent loses 1 life and you gain that much life.)"
+        ),
+        ColorSet::new()
+    );
+    // Dryad Arbor has no mana cost, only a color indicator
+    assert_eq!(
+        face_color_identity(None, None, ColorSet::from_str("G").ok()).name(),
+        "Green"
+    );
+}
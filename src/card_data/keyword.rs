@@ -0,0 +1,385 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::effect::ActivationCost;
+use crate::mana::ManaCost;
+
+// Declares Keyword with one variant per keyword ability, grouped by what
+// follows the keyword: nothing, a mana cost, any cost, a number, a number and
+// a mana cost, or some text
+macro_rules! keywords {
+    (
+        plain { $($plain:ident => $plain_name:literal,)* }
+        mana { $($mana:ident => $mana_name:literal,)* }
+        cost { $($cost:ident => $cost_name:literal,)* }
+        number { $($number:ident => $number_name:literal,)* }
+        number_and_mana { $($number_mana:ident => $number_mana_name:literal,)* }
+        quality { $($quality:ident => $quality_name:literal,)* }
+    ) => {
+        // Keyword abilities @CR 702
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum Keyword {
+            $($plain,)*
+            $($mana(ManaCost),)*
+            $($cost(ActivationCost),)*
+            $($number(usize),)*
+            $($number_mana(usize, ManaCost),)*
+            $($quality(String),)*
+            // "Islandwalk" or "Nonbasic landwalk", with the kind of land @CR 702.14
+            Landwalk(String),
+            // ability words and keyword actions that data sources list along
+            // with keywords, like Landfall or Scry
+            Other(String),
+        }
+
+        impl Keyword {
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Keyword::$plain => $plain_name,)*
+                    $(Keyword::$mana(_) => $mana_name,)*
+                    $(Keyword::$cost(_) => $cost_name,)*
+                    $(Keyword::$number(_) => $number_name,)*
+                    $(Keyword::$number_mana(..) => $number_mana_name,)*
+                    $(Keyword::$quality(_) => $quality_name,)*
+                    Keyword::Landwalk(_) => "Landwalk",
+                    Keyword::Other(name) => name,
+                }
+            }
+        }
+
+        impl FromStr for Keyword {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let s = s.trim().trim_end_matches('.');
+                $(if s.eq_ignore_ascii_case($plain_name) {
+                    return Ok(Keyword::$plain);
+                })*
+                $(if let Some(rest) = parameter(s, $mana_name) {
+                    return ManaCost::from_str(rest)
+                        .map(Keyword::$mana)
+                        .map_err(|e| format!("{} needs a mana cost: {}", $mana_name, e));
+                })*
+                $(if let Some(rest) = parameter(s, $cost_name) {
+                    return ActivationCost::from_str(rest)
+                        .map(Keyword::$cost)
+                        .map_err(|e| format!("{} needs a cost: {}", $cost_name, e));
+                })*
+                $(if let Some(rest) = parameter(s, $number_name) {
+                    return rest
+                        .parse()
+                        .map(Keyword::$number)
+                        .map_err(|_| format!("{} needs a number, not \"{}\"", $number_name, rest));
+                })*
+                $(if let Some(rest) = parameter(s, $number_mana_name) {
+                    let error = || {
+                        format!("{} needs a number and a mana cost, not \"{}\"", $number_mana_name, rest)
+                    };
+                    let (number, cost) = rest.split_once('—').ok_or_else(error)?;
+                    return Ok(Keyword::$number_mana(
+                        number.trim().parse().map_err(|_| error())?,
+                        ManaCost::from_str(cost.trim()).map_err(|_| error())?,
+                    ));
+                })*
+                $(if let Some(rest) = parameter(s, $quality_name) {
+                    return Ok(Keyword::$quality(rest.to_owned()));
+                })*
+                if let Some(land) = landwalk(s) {
+                    return Ok(Keyword::Landwalk(land.to_owned()));
+                }
+                Err(format!("{} is not a keyword", s))
+            }
+        }
+
+        impl Display for Keyword {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Keyword::$plain => f.write_str($plain_name),)*
+                    $(Keyword::$mana(cost) => f.write_fmt(format_args!("{} {}", $mana_name, cost)),)*
+                    $(Keyword::$cost(cost) => {
+                        // costs that aren't just mana are written after a dash
+                        if cost.components.is_empty() {
+                            f.write_fmt(format_args!("{} {}", $cost_name, cost))
+                        } else {
+                            f.write_fmt(format_args!("{}—{}", $cost_name, cost))
+                        }
+                    })*
+                    $(Keyword::$number(n) => f.write_fmt(format_args!("{} {}", $number_name, n)),)*
+                    $(Keyword::$number_mana(n, cost) => f.write_fmt(format_args!("{} {}—{}", $number_mana_name, n, cost)),)*
+                    $(Keyword::$quality(quality) => f.write_fmt(format_args!("{} {}", $quality_name, quality)),)*
+                    Keyword::Landwalk(land) => f.write_fmt(format_args!("{}walk", land)),
+                    Keyword::Other(name) => f.write_str(name),
+                }
+            }
+        }
+    };
+}
+
+// What follows `name` in "Toxic 2", "Ward {2}" or "Ward—Pay 3 life"
+fn parameter<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    let head = s.get(..name.len())?;
+    if !head.eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let rest = &s[name.len()..];
+    rest.strip_prefix(' ')
+        .or_else(|| rest.strip_prefix('—'))
+        .map(str::trim)
+        .filter(|rest| !rest.is_empty())
+}
+
+// The kind of land in "Islandwalk" or "Nonbasic landwalk"
+fn landwalk(s: &str) -> Option<&str> {
+    let land = s.get(..s.len().checked_sub("walk".len())?)?;
+    let walk = &s[land.len()..];
+    (walk.eq_ignore_ascii_case("walk") && !land.is_empty() && !land.ends_with(' ')).then_some(land)
+}
+
+keywords! {
+    plain {
+        Ascend => "Ascend",
+        Assist => "Assist",
+        Banding => "Banding",
+        BattleCry => "Battle cry",
+        Cascade => "Cascade",
+        Changeling => "Changeling",
+        Cipher => "Cipher",
+        Compleated => "Compleated",
+        Conspire => "Conspire",
+        Convoke => "Convoke",
+        Daybound => "Daybound",
+        Deathtouch => "Deathtouch",
+        Decayed => "Decayed",
+        Defender => "Defender",
+        Delve => "Delve",
+        Dethrone => "Dethrone",
+        Devoid => "Devoid",
+        DoubleStrike => "Double strike",
+        Enlist => "Enlist",
+        Epic => "Epic",
+        Evolve => "Evolve",
+        Exalted => "Exalted",
+        Exploit => "Exploit",
+        Extort => "Extort",
+        Fear => "Fear",
+        FirstStrike => "First strike",
+        Flanking => "Flanking",
+        Flash => "Flash",
+        Flying => "Flying",
+        Fuse => "Fuse",
+        Haste => "Haste",
+        Haunt => "Haunt",
+        Hexproof => "Hexproof",
+        Horsemanship => "Horsemanship",
+        Improvise => "Improvise",
+        Indestructible => "Indestructible",
+        Infect => "Infect",
+        Intimidate => "Intimidate",
+        JumpStart => "Jump-start",
+        Lifelink => "Lifelink",
+        LivingMetal => "Living metal",
+        LivingWeapon => "Living weapon",
+        Melee => "Melee",
+        Menace => "Menace",
+        Mentor => "Mentor",
+        Myriad => "Myriad",
+        Nightbound => "Nightbound",
+        Partner => "Partner",
+        Persist => "Persist",
+        Phasing => "Phasing",
+        Provoke => "Provoke",
+        Prowess => "Prowess",
+        Reach => "Reach",
+        ReadAhead => "Read ahead",
+        Rebound => "Rebound",
+        Retrace => "Retrace",
+        Riot => "Riot",
+        Shadow => "Shadow",
+        Shroud => "Shroud",
+        Skulk => "Skulk",
+        Soulbond => "Soulbond",
+        SplitSecond => "Split second",
+        Storm => "Storm",
+        Sunburst => "Sunburst",
+        Training => "Training",
+        Trample => "Trample",
+        Undaunted => "Undaunted",
+        Undying => "Undying",
+        Unleash => "Unleash",
+        Vigilance => "Vigilance",
+        Wither => "Wither",
+    }
+    mana {
+        Bestow => "Bestow",
+        Blitz => "Blitz",
+        Buyback => "Buyback",
+        Dash => "Dash",
+        Disguise => "Disguise",
+        Disturb => "Disturb",
+        Embalm => "Embalm",
+        Emerge => "Emerge",
+        Encore => "Encore",
+        Entwine => "Entwine",
+        Equip => "Equip",
+        Eternalize => "Eternalize",
+        Evoke => "Evoke",
+        Foretell => "Foretell",
+        Kicker => "Kicker",
+        LevelUp => "Level up",
+        Madness => "Madness",
+        Megamorph => "Megamorph",
+        Miracle => "Miracle",
+        Morph => "Morph",
+        Multikicker => "Multikicker",
+        Mutate => "Mutate",
+        Ninjutsu => "Ninjutsu",
+        Offspring => "Offspring",
+        Outlast => "Outlast",
+        Overload => "Overload",
+        Plot => "Plot",
+        Prowl => "Prowl",
+        Reconfigure => "Reconfigure",
+        Scavenge => "Scavenge",
+        Spectacle => "Spectacle",
+        Squad => "Squad",
+        Surge => "Surge",
+        Transmute => "Transmute",
+        Unearth => "Unearth",
+    }
+    cost {
+        CumulativeUpkeep => "Cumulative upkeep",
+        Cycling => "Cycling",
+        Echo => "Echo",
+        Escape => "Escape",
+        Flashback => "Flashback",
+        Ward => "Ward",
+    }
+    number {
+        Absorb => "Absorb",
+        Afflict => "Afflict",
+        Afterlife => "Afterlife",
+        Amplify => "Amplify",
+        Annihilator => "Annihilator",
+        Backup => "Backup",
+        Bloodthirst => "Bloodthirst",
+        Bushido => "Bushido",
+        Casualty => "Casualty",
+        Crew => "Crew",
+        Devour => "Devour",
+        Dredge => "Dredge",
+        Fabricate => "Fabricate",
+        Fading => "Fading",
+        Frenzy => "Frenzy",
+        Graft => "Graft",
+        Modular => "Modular",
+        Poisonous => "Poisonous",
+        Rampage => "Rampage",
+        Renown => "Renown",
+        Ripple => "Ripple",
+        Soulshift => "Soulshift",
+        Toxic => "Toxic",
+        Tribute => "Tribute",
+        Vanishing => "Vanishing",
+    }
+    number_and_mana {
+        Awaken => "Awaken",
+        Suspend => "Suspend",
+    }
+    quality {
+        Affinity => "Affinity for",
+        Enchant => "Enchant",
+        HexproofFrom => "Hexproof from",
+        PartnerWith => "Partner with",
+        Protection => "Protection from",
+    }
+}
+
+impl Keyword {
+    // MTGJSON and Scryfall only list keyword names, the parameters are in the
+    // rules text, e.g. "Reconfigure" and "Reconfigure {R} (...)".
+    pub fn from_name_and_text(name: &str, text: Option<&str>) -> Self {
+        if let Ok(keyword) = Keyword::from_str(name) {
+            return keyword;
+        }
+        let lower = name.to_lowercase();
+        text.into_iter()
+            .flat_map(str::lines)
+            // reminder text comes after the keyword
+            .map(|line| line.split(" (").next().unwrap_or(line))
+            .flat_map(|line| line.split(", "))
+            .find(|phrase| {
+                let phrase = phrase.to_lowercase();
+                phrase.starts_with(&lower)
+                    && !phrase[lower.len()..].starts_with(|c: char| c.is_alphabetic())
+            })
+            .and_then(|phrase| Keyword::from_str(phrase).ok())
+            .unwrap_or_else(|| Keyword::Other(name.to_owned()))
+    }
+}
+
+#[test]
+fn parses_keywords() {
+    let round_trip = |s: &str| Keyword::from_str(s).unwrap().to_string();
+    for keyword in [
+        "Flying",
+        "Double strike",
+        "Ward {2}",
+        "Ward—Pay 3 life",
+        "Reconfigure {R}",
+        "Toxic 2",
+        "Protection from red",
+        "Enchant creature",
+        "Equip {1}",
+        "Crew 3",
+        "Suspend 4—{U}",
+        "Awaken 3—{4}{U}",
+        "Mutate {3}{G}",
+        "Cumulative upkeep {1}",
+        "Partner",
+        "Partner with Pir, Imaginative Rascal",
+        "Hexproof from black",
+        "Islandwalk",
+        "Nonbasic landwalk",
+    ] {
+        assert_eq!(round_trip(keyword), keyword);
+    }
+    assert_eq!(Keyword::from_str("first strike"), Ok(Keyword::FirstStrike));
+    assert_eq!(Keyword::from_str("Toxic 2"), Ok(Keyword::Toxic(2)));
+    assert_eq!(
+        Keyword::from_str("Toxic many"),
+        Err("Toxic needs a number, not \"many\"".to_owned())
+    );
+    assert!(Keyword::from_str("Reconfigure").is_err());
+    assert!(Keyword::from_str("Landfall").is_err());
+    assert_eq!(
+        Keyword::from_str("Forestwalk"),
+        Ok(Keyword::Landwalk("Forest".to_owned()))
+    );
+    assert_eq!(Keyword::from_str("Islandwalk").unwrap().name(), "Landwalk");
+    assert_eq!(
+        Keyword::from_str("Suspend 4"),
+        Err("Suspend needs a number and a mana cost, not \"4\"".to_owned())
+    );
+
+    // Rabbit Battery
+    let text = "Haste\nEquipped creature gets +1/+1 and has haste.\nReconfigure {R} ({R}: Attach to target creature you control; or unattach from a creature. Reconfigure only as a sorcery. While attached, this isn't a creature.)";
+    assert_eq!(
+        Keyword::from_name_and_text("Reconfigure", Some(text)).to_string(),
+        "Reconfigure {R}"
+    );
+    assert_eq!(
+        Keyword::from_name_and_text("Haste", Some(text)),
+        Keyword::Haste
+    );
+    assert_eq!(
+        Keyword::from_name_and_text("Ward", Some("Flying, ward {2}")).to_string(),
+        "Ward {2}"
+    );
+    assert_eq!(
+        Keyword::from_name_and_text("Suspend", Some("Suspend 4—{U} (Rather than cast this card from your hand, pay {U} and exile it with four time counters on it.)")).to_string(),
+        "Suspend 4—{U}"
+    );
+    assert_eq!(
+        Keyword::from_name_and_text("Landfall", Some("Landfall — Whenever a land enters")),
+        Keyword::Other("Landfall".to_owned())
+    );
+}
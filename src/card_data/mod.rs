@@ -1,35 +1,64 @@
-use crate::mana::ManaCost;
+use crate::mana::{ColorSet, ManaCost};
 use enum_kinds::EnumKind;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
+mod card;
 mod card_serde;
+mod color_identity;
+mod keyword;
+#[cfg(test)]
 mod tests;
+mod type_line;
 pub mod types;
 
+pub use card::{Card, CardLayout, MtgJsonCard};
+pub use color_identity::{face_color_identity, rules_text_colors};
+pub use keyword::Keyword;
+pub use type_line::TypeLine;
+
 use types::{
-    ArtifactData, ArtifactSubtype, BattleData, CreatureData, CreatureType, EnchantmentData,
-    EnchantmentType, InstantData, LandData, LandType, PlaneswalkerData, PlaneswalkerType,
-    SorceryData, TribalData,
+    ArtifactData, BattleData, CreatureData, EnchantmentData, InstantData, KindredData, LandData,
+    LoyaltyAbility, PlaneswalkerData, SorceryData,
 };
 
-#[derive(Debug, Serialize, Deserialize)]
+// @CR 205.4a, plus the supertypes of un-sets and Archenemy schemes
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    strum_macros::Display,
+    strum_macros::EnumIter,
+)]
 pub enum CardSupertype {
     Basic,
     Legendary,
+    Ongoing,
     Snow,
+    World,
+    Elite,
+    Host,
+}
+
+impl FromStr for CardSupertype {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        Self::iter()
+            .find(|supertype| supertype.to_string() == s)
+            .ok_or(format!("{} is not a supertype", s))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 #[derive(EnumKind)]
-#[enum_kind(
-    CardType,
-    derive(
-        strum_macros::EnumIter,
-        strum_macros::EnumString,
-        strum_macros::Display
-    )
-)]
+#[enum_kind(CardType, derive(strum_macros::EnumIter, strum_macros::Display))]
 pub enum CardTypeData {
     Land(LandData),
     Creature(CreatureData),
@@ -37,26 +66,103 @@ pub enum CardTypeData {
     Sorcery(SorceryData),
     Artifact(ArtifactData),
     Enchantment(EnchantmentData),
-    Tribal(TribalData),
+    Kindred(KindredData),
     Planeswalker(PlaneswalkerData),
     Battle(BattleData),
 }
 
-use card_serde::FlatCardTypeData;
-use serde_with::{serde_as, DisplayFromStr, FromInto};
-#[serde_as]
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+// Kindred used to be called Tribal, which older data still uses
+impl FromStr for CardType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use strum::IntoEnumIterator;
+        if s == "Tribal" {
+            return Ok(CardType::Kindred);
+        }
+        CardType::iter()
+            .find(|card_type| card_type.to_string() == s)
+            .ok_or(format!("{} is not a card type", s))
+    }
+}
+
+pub use card_serde::{serialize_as_tribal, serialize_with_kindred_name, KindredName};
+// Serialize and Deserialize are implemented by hand in card_serde
+#[derive(Debug, Clone)]
 pub struct CardData {
     pub name: String,
-    #[serde_as(as = "Option<DisplayFromStr>")]
     pub mana_cost: Option<ManaCost>,
+    pub text: Option<String>,
+    pub flavor_text: Option<String>,
+    pub keywords: Vec<Keyword>,
+    // the colors as given by the data, see colors() @CR 202.2
+    pub colors: Option<ColorSet>,
+    pub color_indicator: Option<ColorSet>,
+    pub color_identity: Option<ColorSet>,
     pub supertypes: Vec<CardSupertype>,
-    #[serde_as(as = "FromInto<FlatCardTypeData>")]
-    #[serde(flatten)]
     pub type_data: Vec<CardTypeData>,
 }
 
+impl CardData {
+    pub fn has_supertype(&self, supertype: CardSupertype) -> bool {
+        self.supertypes.contains(&supertype)
+    }
+    pub fn is_basic(&self) -> bool {
+        self.has_supertype(CardSupertype::Basic)
+    }
+    pub fn is_legendary(&self) -> bool {
+        self.has_supertype(CardSupertype::Legendary)
+    }
+    pub fn is_snow(&self) -> bool {
+        self.has_supertype(CardSupertype::Snow)
+    }
+    pub fn is_world(&self) -> bool {
+        self.has_supertype(CardSupertype::World)
+    }
+    // A card's colors come from its mana cost and color indicator, unless the
+    // data says otherwise e.g. because of a characteristic-defining ability @CR 202.2
+    pub fn colors(&self) -> ColorSet {
+        self.colors.unwrap_or_else(|| {
+            self.mana_cost
+                .as_ref()
+                .map(ManaCost::get_colors)
+                .unwrap_or_default()
+                | self.color_indicator.unwrap_or_default()
+        })
+    }
+    pub fn has_keyword(&self, name: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|keyword| keyword.name().eq_ignore_ascii_case(name))
+    }
+    // The type line as it would be printed on the card
+    pub fn type_line(&self) -> String {
+        type_line::format_type_line(&self.supertypes, &self.type_data)
+    }
+    pub fn is_planeswalker(&self) -> bool {
+        self.type_data
+            .iter()
+            .any(|data| matches!(data, CardTypeData::Planeswalker(_)))
+    }
+    // A planeswalker's loyalty abilities, read from its rules text so they
+    // always match it. Each prints back as the text it was read from, except
+    // that a cost typed with a hyphen ("-2") prints with the minus sign ("−2").
+    pub fn loyalty_abilities(&self) -> Vec<LoyaltyAbility> {
+        self.planeswalker_abilities()
+            .filter_map(|ability| LoyaltyAbility::from_str(&ability).ok())
+            .collect()
+    }
+    // The rest of a planeswalker's rules text, e.g. "Jace can be your commander."
+    pub fn static_abilities(&self) -> Vec<String> {
+        self.planeswalker_abilities()
+            .filter(|ability| LoyaltyAbility::from_str(ability).is_err())
+            .collect()
+    }
+    fn planeswalker_abilities(&self) -> impl Iterator<Item = String> {
+        let text = self.text.as_deref().filter(|_| self.is_planeswalker());
+        types::abilities(text.unwrap_or_default()).into_iter()
+    }
+}
+
 pub fn test_serialize() -> Vec<String> {
     let bears: CardData = serde_json::from_value(serde_json::json!({
         "name": "Grizzly Bears",
@@ -46,3 +46,784 @@ fn reconfigure() {
     ).unwrap();
     println!("{:#?}", card);
 }
+
+// Cards in the flat MTGJSON layout, with every field CardData keeps
+fn round_trip_corpus() -> Vec<serde_json::Value> {
+    use serde_json::json;
+    vec![
+        json!({
+            "name": "Grizzly Bears",
+            "manaCost": "{1}{G}",
+            "supertypes": [],
+            "types": ["Creature"],
+            "subtypes": ["Bear"],
+            "power": "2",
+            "toughness": "2"
+        }),
+        json!({
+            "name": "Dryad Arbor",
+            "manaCost": null,
+            "supertypes": [],
+            "types": ["Land", "Creature"],
+            "subtypes": ["Forest", "Dryad"],
+            "power": "1",
+            "toughness": "1"
+        }),
+        json!({
+            "name": "Rabbit Battery",
+            "manaCost": "{R}",
+            "supertypes": [],
+            "types": ["Artifact", "Creature"],
+            "subtypes": ["Equipment", "Rabbit"],
+            "power": "1",
+            "toughness": "1"
+        }),
+        json!({
+            "name": "Forest",
+            "manaCost": null,
+            "supertypes": ["Basic"],
+            "types": ["Land"],
+            "subtypes": ["Forest"]
+        }),
+        json!({
+            "name": "Pacifism",
+            "manaCost": "{1}{W}",
+            "supertypes": [],
+            "types": ["Enchantment"],
+            "subtypes": ["Aura"]
+        }),
+        json!({
+            "name": "Bitterblossom",
+            "manaCost": "{1}{B}",
+            "supertypes": [],
+            "types": ["Kindred", "Enchantment"],
+            "subtypes": ["Faerie"]
+        }),
+        json!({
+            "name": "Jace Beleren",
+            "manaCost": "{1}{U}{U}",
+            "supertypes": ["Legendary"],
+            "types": ["Planeswalker"],
+            "subtypes": ["Jace"],
+            "loyalty": "3"
+        }),
+        json!({
+            "name": "Tarmogoyf",
+            "manaCost": "{1}{G}",
+            "supertypes": [],
+            "types": ["Creature"],
+            "subtypes": ["Lhurgoyf"],
+            "power": "*",
+            "toughness": "*"
+        }),
+        json!({
+            "name": "Lightning Bolt",
+            "manaCost": "{R}",
+            "supertypes": [],
+            "types": ["Instant"],
+            "subtypes": []
+        }),
+        json!({
+            "name": "Rite of Consumption",
+            "manaCost": "{1}{B}",
+            "supertypes": [],
+            "types": ["Sorcery"],
+            "subtypes": []
+        }),
+        json!({
+            "name": "Crib Swap",
+            "manaCost": "{2}{W}",
+            "supertypes": [],
+            "types": ["Kindred", "Instant"],
+            "subtypes": ["Shapeshifter"]
+        }),
+        json!({
+            "name": "Invasion of Tarkir",
+            "manaCost": "{1}{R}",
+            "supertypes": [],
+            "types": ["Battle"],
+            "subtypes": ["Siege"],
+            "defense": "5"
+        }),
+    ]
+}
+
+#[test]
+fn round_trips_json() {
+    for card in round_trip_corpus() {
+        let data: CardData = serde_json::from_value(card.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&data).unwrap(), card);
+    }
+}
+
+#[test]
+fn round_trips_ron() {
+    for card in round_trip_corpus() {
+        let data: CardData = serde_json::from_value(card.clone()).unwrap();
+        let text = ron::to_string(&data).unwrap();
+        let data: CardData = ron::from_str(&text).unwrap();
+        assert_eq!(serde_json::to_value(&data).unwrap(), card, "{}", text);
+    }
+}
+
+// The corpus plus a card with every optional field, for the binary formats
+fn binary_corpus() -> Vec<serde_json::Value> {
+    let mut corpus = round_trip_corpus();
+    corpus.push(serde_json::json!({
+        "name": "Rabbit Battery",
+        "manaCost": "{R}",
+        "text": "Haste\nEquipped creature gets +1/+1 and has haste.\nReconfigure {R}",
+        "flavorText": "A jolt of energy.",
+        "keywords": ["Haste", "Reconfigure {R}"],
+        "colors": ["R"],
+        "colorIndicator": ["R"],
+        "colorIdentity": ["R"],
+        "supertypes": [],
+        "types": ["Artifact", "Creature"],
+        "subtypes": ["Equipment", "Rabbit"],
+        "power": "1",
+        "toughness": "1"
+    }));
+    corpus
+}
+
+#[test]
+fn round_trips_bincode() {
+    for card in binary_corpus() {
+        let data: CardData = serde_json::from_value(card.clone()).unwrap();
+        let bytes = bincode::serialize(&data).unwrap();
+        let data: CardData = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(serde_json::to_value(&data).unwrap(), card);
+    }
+}
+
+#[test]
+fn round_trips_messagepack() {
+    for card in binary_corpus() {
+        let data: CardData = serde_json::from_value(card.clone()).unwrap();
+        let bytes = rmp_serde::to_vec(&data).unwrap();
+        let data: CardData = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(serde_json::to_value(&data).unwrap(), card);
+    }
+}
+
+#[test]
+fn round_trips_cards_in_binary_formats() {
+    use super::{Card, CardLayout};
+    let fire_ice: Card = serde_json::from_value(serde_json::json!({
+        "name": "Fire // Ice",
+        "layout": "split",
+        "card_faces": [
+            {"name": "Fire", "mana_cost": "{1}{R}", "type_line": "Instant"},
+            {"name": "Ice", "mana_cost": "{1}{U}", "type_line": "Instant"}
+        ]
+    }))
+    .unwrap();
+    assert_eq!(
+        Card::new("Fire // Ice".to_owned(), CardLayout::Split, vec![]).unwrap_err(),
+        "Fire // Ice has no faces"
+    );
+    let faceless = rmp_serde::to_vec(&("Fire // Ice", "split", Vec::<CardData>::new())).unwrap();
+    assert_eq!(
+        rmp_serde::from_slice::<Card>(&faceless)
+            .unwrap_err()
+            .to_string(),
+        "Fire // Ice has no faces"
+    );
+    let expected = serde_json::to_value(&fire_ice).unwrap();
+    let bytes = bincode::serialize(&fire_ice).unwrap();
+    let card: Card = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(serde_json::to_value(&card).unwrap(), expected);
+    // MessagePack writes structs as arrays by default, or as maps
+    let bytes = rmp_serde::to_vec(&fire_ice).unwrap();
+    let card: Card = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(serde_json::to_value(&card).unwrap(), expected);
+    let bytes = rmp_serde::to_vec_named(&fire_ice).unwrap();
+    let card: Card = rmp_serde::from_slice(&bytes).unwrap();
+    assert_eq!(serde_json::to_value(&card).unwrap(), expected);
+}
+
+#[test]
+fn type_data_keeps_subtypes() {
+    use super::types::{CreatureData, LandData};
+    let bear: CreatureData = serde_json::from_value(serde_json::json!({
+        "power": "2",
+        "toughness": "2",
+        "subtypes": ["Bear"]
+    }))
+    .unwrap();
+    assert_eq!(bear.creature_types[0].to_string(), "Bear");
+    assert_eq!(
+        serde_json::to_value(&bear).unwrap(),
+        serde_json::json!({"power": "2", "toughness": "2", "subtypes": ["Bear"]})
+    );
+    let forest: LandData = ron::from_str(&ron::to_string(&LandData::default()).unwrap()).unwrap();
+    assert!(forest.land_types.is_empty());
+    assert!(serde_json::from_value::<CreatureData>(serde_json::json!({
+        "power": "2",
+        "toughness": "2",
+        "subtypes": ["Forest"]
+    }))
+    .is_err());
+}
+
+#[test]
+fn streams_card_data() {
+    // a reader can't lend out strings, and MTGJSON has plenty of fields we skip
+    let text = r#"{
+        "name": "Dryad Arbor",
+        "colors": ["G"],
+        "subtypes": ["Forest", "Dryad"],
+        "identifiers": {"scryfallId": "aeaf7c4d"},
+        "power": "1",
+        "toughness": "1",
+        "supertypes": [],
+        "types": ["Land", "Creature"]
+    }"#;
+    let arbor: CardData = serde_json::from_reader(text.as_bytes()).unwrap();
+    assert_eq!(
+        serde_json::to_value(&arbor).unwrap(),
+        serde_json::json!({
+            "name": "Dryad Arbor",
+            "manaCost": null,
+            "colors": ["G"],
+            "supertypes": [],
+            "types": ["Land", "Creature"],
+            "subtypes": ["Forest", "Dryad"],
+            "power": "1",
+            "toughness": "1"
+        })
+    );
+
+    let error = |text: &str| {
+        serde_json::from_str::<CardData>(text)
+            .unwrap_err()
+            .to_string()
+    };
+    assert!(error(r#"{"name": "Ornithopter", "supertypes": [], "types": ["Artifact", "Creature"], "subtypes": []}"#)
+        .contains("missing field `power`"));
+    assert!(error(
+        r#"{"name": "Forest", "supertypes": [], "types": ["Land"], "subtypes": ["Bear"]}"#
+    )
+    .contains("Invalid subtypes: [\"Bear\"]"));
+}
+
+#[test]
+fn keeps_data_for_every_card_type() {
+    use super::{CardType, CardTypeData};
+    use strum::IntoEnumIterator;
+    let corpus = round_trip_corpus()
+        .into_iter()
+        .map(|card| serde_json::from_value::<CardData>(card).unwrap())
+        .collect::<Vec<_>>();
+    for card_type in CardType::iter() {
+        assert!(
+            corpus.iter().any(|card| card
+                .type_data
+                .iter()
+                .any(|data| CardType::from(data) == card_type)),
+            "no card in the corpus kept its {} data",
+            card_type
+        );
+    }
+    for card in &corpus {
+        assert!(
+            !card.type_data.is_empty(),
+            "{} lost its type data",
+            card.name
+        );
+    }
+
+    let invasion = corpus
+        .iter()
+        .find(|card| card.name == "Invasion of Tarkir")
+        .unwrap();
+    match &invasion.type_data[..] {
+        [CardTypeData::Battle(battle)] => {
+            assert_eq!(battle.defense.to_string(), "5");
+            assert_eq!(battle.battle_types[0].to_string(), "Siege");
+        }
+        other => panic!("expected battle data, got {:?}", other),
+    }
+}
+
+#[test]
+fn reads_supertypes() {
+    use super::CardSupertype;
+    use std::str::FromStr;
+    use strum::IntoEnumIterator;
+    for supertype in CardSupertype::iter() {
+        assert_eq!(
+            CardSupertype::from_str(&supertype.to_string()),
+            Ok(supertype)
+        );
+    }
+    assert_eq!(
+        CardSupertype::from_str("Mythic"),
+        Err("Mythic is not a supertype".to_owned())
+    );
+
+    let card = |supertypes: serde_json::Value| {
+        serde_json::from_value::<CardData>(serde_json::json!({
+            "name": "The Abyss",
+            "manaCost": "{3}{B}",
+            "supertypes": supertypes,
+            "types": ["Enchantment"],
+            "subtypes": []
+        }))
+    };
+    let abyss = card(serde_json::json!(["World"])).unwrap();
+    assert!(abyss.is_world());
+    assert!(!abyss.is_legendary());
+    let error = card(serde_json::json!(["Legendary", "Mythic"])).unwrap_err();
+    assert_eq!(error.to_string(), "Mythic is not a supertype");
+}
+
+#[test]
+fn reads_scryfall_type_line() {
+    let card: CardData = serde_json::from_value(serde_json::json!({
+        "name": "Rabbit Battery",
+        "type_line": "Artifact Creature — Equipment Rabbit",
+        "power": "1",
+        "toughness": "1"
+    }))
+    .unwrap();
+    assert!(card.supertypes.is_empty());
+    assert_eq!(card.type_line(), "Artifact Creature — Equipment Rabbit");
+    for card in round_trip_corpus() {
+        let data: CardData = serde_json::from_value(card).unwrap();
+        let mut scryfall = serde_json::to_value(&data).unwrap();
+        let fields = scryfall.as_object_mut().unwrap();
+        fields.remove("types");
+        fields.remove("subtypes");
+        fields.remove("supertypes");
+        fields.insert("type_line".to_owned(), data.type_line().into());
+        let from_line: CardData = serde_json::from_value(scryfall).unwrap();
+        assert_eq!(from_line.type_line(), data.type_line());
+    }
+}
+
+#[test]
+fn reads_tribal_as_kindred() {
+    use super::{serialize_as_tribal, serialize_with_kindred_name, KindredName};
+    let bitterblossom = serde_json::json!({
+        "name": "Bitterblossom",
+        "manaCost": "{1}{B}",
+        "supertypes": [],
+        "types": ["Tribal", "Enchantment"],
+        "subtypes": ["Faerie"]
+    });
+    let data: CardData = serde_json::from_value(bitterblossom.clone()).unwrap();
+    assert_eq!(data.type_line(), "Kindred Enchantment — Faerie");
+    assert_eq!(
+        serde_json::to_value(&data).unwrap()["types"],
+        serde_json::json!(["Kindred", "Enchantment"])
+    );
+    let tribal =
+        serialize_with_kindred_name(&data, KindredName::Tribal, serde_json::value::Serializer)
+            .unwrap();
+    assert_eq!(tribal, bitterblossom);
+
+    // the name can be chosen per field, and works from any thread
+    #[derive(serde::Serialize)]
+    struct Export {
+        #[serde(serialize_with = "serialize_as_tribal")]
+        card: CardData,
+    }
+    let export = Export { card: data };
+    let exported = std::thread::scope(|scope| {
+        scope
+            .spawn(|| serde_json::to_value(&export).unwrap())
+            .join()
+            .unwrap()
+    });
+    assert_eq!(exported["card"], bitterblossom);
+    assert_eq!(
+        serde_json::to_value(&export.card).unwrap()["types"][0],
+        serde_json::json!("Kindred")
+    );
+}
+
+#[test]
+fn reads_scryfall_card_faces() {
+    use super::{Card, CardLayout};
+    use crate::game_state::Zone;
+    let fire_ice: Card = serde_json::from_value(serde_json::json!({
+        "object": "card",
+        "name": "Fire // Ice",
+        "layout": "split",
+        "mana_cost": "{1}{R} // {1}{U}",
+        "type_line": "Instant // Instant",
+        "card_faces": [
+            {
+                "object": "card_face",
+                "name": "Fire",
+                "mana_cost": "{1}{R}",
+                "type_line": "Instant",
+                "oracle_text": "Fire deals 2 damage divided as you choose among one or two targets."
+            },
+            {
+                "object": "card_face",
+                "name": "Ice",
+                "mana_cost": "{1}{U}",
+                "type_line": "Instant",
+                "oracle_text": "Tap target permanent.\nDraw a card."
+            }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(fire_ice.layout, CardLayout::Split);
+    assert_eq!(fire_ice.front_face().name, "Fire");
+    assert!(fire_ice.back_face().is_none());
+    assert_eq!(fire_ice.characteristics(Zone::Hand, 0).len(), 2);
+    let ice = fire_ice.characteristics(Zone::Stack, 1);
+    assert_eq!(ice[0].name, "Ice");
+    assert_eq!(fire_ice.color_identity().to_string(), "UR");
+
+    let fable: Card = serde_json::from_value(serde_json::json!({
+        "name": "Fable of the Mirror-Breaker // Reflection of Kiki-Jiki",
+        "layout": "transform",
+        "card_faces": [
+            {
+                "name": "Fable of the Mirror-Breaker",
+                "mana_cost": "{2}{R}",
+                "type_line": "Enchantment — Saga",
+                "oracle_text": "(As this Saga enters and after your draw step, add a lore counter. Sacrifice after III.)"
+            },
+            {
+                "name": "Reflection of Kiki-Jiki",
+                "mana_cost": "",
+                "type_line": "Enchantment Creature — Goblin Shaman",
+                "oracle_text": "{1}, {T}: Create a token that's a copy of another target nonlegendary creature you control, except it has haste. Sacrifice it at the beginning of the next end step.",
+                "power": "2",
+                "toughness": "2"
+            }
+        ]
+    }))
+    .unwrap();
+    let reflection = fable.back_face().unwrap();
+    assert_eq!(reflection.mana_cost, None);
+    assert_eq!(
+        reflection.type_line(),
+        "Enchantment Creature — Goblin Shaman"
+    );
+    assert_eq!(
+        fable.characteristics(Zone::Graveyard, 1)[0].name,
+        fable.faces()[0].name
+    );
+    assert_eq!(
+        fable.characteristics(Zone::Battlefield, 1)[0].name,
+        "Reflection of Kiki-Jiki"
+    );
+
+    // single faced cards keep their face next to the card's fields
+    let bolt: Card = serde_json::from_value(serde_json::json!({
+        "name": "Lightning Bolt",
+        "layout": "normal",
+        "mana_cost": "{R}",
+        "type_line": "Instant",
+        "oracle_text": "Lightning Bolt deals 3 damage to any target."
+    }))
+    .unwrap();
+    assert_eq!(bolt.faces().len(), 1);
+    assert_eq!(
+        bolt.front_face().text.as_deref(),
+        Some("Lightning Bolt deals 3 damage to any target.")
+    );
+
+    let text = ron::to_string(&fable).unwrap();
+    let fable: Card = ron::from_str(&text).unwrap();
+    assert_eq!(fable.faces().len(), 2);
+    assert_eq!(fable.layout, CardLayout::Transform);
+}
+
+#[test]
+fn gives_scryfall_card_keywords_to_faces() {
+    use super::{Card, Keyword};
+    let cathar: Card = serde_json::from_value(serde_json::json!({
+        "name": "Brutal Cathar // Moonrage Brute",
+        "layout": "transform",
+        "keywords": ["Daybound", "Nightbound", "Ward"],
+        "card_faces": [
+            {
+                "name": "Brutal Cathar",
+                "mana_cost": "{2}{W}",
+                "type_line": "Creature — Human Soldier Werewolf",
+                "oracle_text": "When this creature enters or transforms into Brutal Cathar, exile target creature an opponent controls until this creature leaves the battlefield.\nDaybound (If a player casts no spells during their own turn, it becomes night next turn.)",
+                "power": "3",
+                "toughness": "3"
+            },
+            {
+                "name": "Moonrage Brute",
+                "mana_cost": "",
+                "type_line": "Creature — Werewolf",
+                "oracle_text": "First strike\nWard—Pay 3 life.\nNightbound (If a player casts at least two spells during their own turn, it becomes day next turn.)",
+                "power": "3",
+                "toughness": "3"
+            }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(cathar.faces()[0].keywords, [Keyword::Daybound]);
+    assert_eq!(
+        cathar.faces()[1]
+            .keywords
+            .iter()
+            .map(|keyword| keyword.to_string())
+            .collect::<Vec<_>>(),
+        ["Nightbound", "Ward—Pay 3 life"]
+    );
+
+    // a keyword no face's text mentions stays with the front face
+    let card: Card = serde_json::from_value(serde_json::json!({
+        "name": "Front // Back",
+        "layout": "modal_dfc",
+        "keywords": ["Flying"],
+        "card_faces": [
+            {"name": "Front", "type_line": "Sorcery", "oracle_text": "Draw a card."},
+            {"name": "Back", "type_line": "Land", "oracle_text": "{T}: Add {W}."}
+        ]
+    }))
+    .unwrap();
+    assert_eq!(card.faces()[0].keywords, [Keyword::Flying]);
+    assert!(card.faces()[1].keywords.is_empty());
+}
+
+#[test]
+fn joins_mtgjson_faces() {
+    use super::{Card, CardLayout, MtgJsonCard};
+    let entries: Vec<MtgJsonCard> = serde_json::from_value(serde_json::json!([
+        {
+            "uuid": "fire", "otherFaceIds": ["ice"], "side": "a", "layout": "split",
+            "name": "Fire // Ice", "faceName": "Fire", "manaCost": "{1}{R}",
+            "supertypes": [], "types": ["Instant"], "subtypes": []
+        },
+        {
+            "uuid": "ice", "otherFaceIds": ["fire"], "side": "b", "layout": "split",
+            "name": "Fire // Ice", "faceName": "Ice", "manaCost": "{1}{U}",
+            "supertypes": [], "types": ["Instant"], "subtypes": []
+        },
+        {
+            "uuid": "bruna", "otherFaceIds": ["brisela", "gisela"], "side": "a", "layout": "meld",
+            "name": "Bruna, the Fading Light // Brisela, Voice of Nightmares",
+            "faceName": "Bruna, the Fading Light", "manaCost": "{5}{W}{W}",
+            "supertypes": ["Legendary"], "types": ["Creature"], "subtypes": ["Angel", "Horror"],
+            "power": "5", "toughness": "7"
+        },
+        {
+            "uuid": "gisela", "otherFaceIds": ["brisela", "bruna"], "side": "a", "layout": "meld",
+            "name": "Gisela, the Broken Blade // Brisela, Voice of Nightmares",
+            "faceName": "Gisela, the Broken Blade", "manaCost": "{2}{W}{W}",
+            "supertypes": ["Legendary"], "types": ["Creature"], "subtypes": ["Angel", "Horror"],
+            "power": "4", "toughness": "3"
+        },
+        {
+            "uuid": "brisela", "otherFaceIds": ["bruna", "gisela"], "side": "b", "layout": "meld",
+            "name": "Brisela, Voice of Nightmares", "faceName": "Brisela, Voice of Nightmares",
+            "supertypes": ["Legendary"], "types": ["Creature"], "subtypes": ["Eldrazi", "Angel"],
+            "power": "9", "toughness": "10"
+        },
+        {
+            "uuid": "bears", "layout": "normal", "name": "Grizzly Bears", "manaCost": "{1}{G}",
+            "supertypes": [], "types": ["Creature"], "subtypes": ["Bear"],
+            "power": "2", "toughness": "2"
+        }
+    ]))
+    .unwrap();
+    let cards = Card::from_mtgjson(entries);
+    let names = cards
+        .iter()
+        .map(|card| {
+            card.faces()
+                .iter()
+                .map(|face| face.name.as_str())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            vec!["Fire", "Ice"],
+            vec!["Bruna, the Fading Light", "Brisela, Voice of Nightmares"],
+            vec!["Gisela, the Broken Blade", "Brisela, Voice of Nightmares"],
+            vec!["Grizzly Bears"],
+        ]
+    );
+    assert_eq!(cards[0].name, "Fire // Ice");
+    assert_eq!(cards[1].layout, CardLayout::Meld);
+    assert_eq!(
+        cards[1].back_face().unwrap().name,
+        "Brisela, Voice of Nightmares"
+    );
+    assert_eq!(cards[3].layout, CardLayout::Normal);
+}
+
+#[test]
+fn reads_text_keywords_and_colors() {
+    use super::Keyword;
+    use crate::mana::{Color, ColorSet};
+    let battery: CardData = serde_json::from_value(serde_json::json!({
+        "name": "Rabbit Battery",
+        "manaCost": "{R}",
+        "text": "Haste\nEquipped creature gets +1/+1 and has haste.\nReconfigure {R} ({R}: Attach to target creature you control; or unattach from a creature. Reconfigure only as a sorcery. While attached, this isn't a creature.)",
+        "flavorText": "A jolt of energy.",
+        "keywords": ["Haste", "Reconfigure"],
+        "colors": ["R"],
+        "colorIdentity": ["R"],
+        "supertypes": [],
+        "types": ["Artifact", "Creature"],
+        "subtypes": ["Equipment", "Rabbit"],
+        "power": "1",
+        "toughness": "1"
+    }))
+    .unwrap();
+    assert_eq!(battery.keywords[0], Keyword::Haste);
+    assert_eq!(battery.keywords[1].to_string(), "Reconfigure {R}");
+    assert!(battery.has_keyword("reconfigure"));
+    assert!(!battery.has_keyword("Flying"));
+    assert_eq!(battery.flavor_text.as_deref(), Some("A jolt of energy."));
+    assert_eq!(battery.colors(), ColorSet::from(Color::Red));
+    let again: CardData = serde_json::from_value(serde_json::to_value(&battery).unwrap()).unwrap();
+    assert_eq!(again.keywords, battery.keywords);
+    assert_eq!(again.text, battery.text);
+
+    // a color indicator gives a card with no mana cost its colors @CR 202.2
+    let ancestral: CardData = serde_json::from_value(serde_json::json!({
+        "name": "Ancestral Vision",
+        "text": "Suspend 4—{U}",
+        "keywords": ["Suspend"],
+        "color_indicator": ["U"],
+        "type_line": "Sorcery"
+    }))
+    .unwrap();
+    assert_eq!(ancestral.colors(), ColorSet::from(Color::Blue));
+    assert_eq!(ancestral.color_identity(), ColorSet::from(Color::Blue));
+    assert_eq!(
+        ancestral.keywords,
+        vec![Keyword::Suspend(4, "{U}".parse().unwrap())]
+    );
+}
+
+#[test]
+fn reads_loyalty_abilities() {
+    use super::types::LoyaltyCost;
+    let text = "+1: Up to one target creature gets +2/+0 until end of turn.\n−X: Jace deals X damage to target creature.\n0: Draw a card.\n−8: You get an emblem with \"Spells you cast cost {1} less to cast.\"\nJace can be your commander.";
+    let jace: CardData = serde_json::from_value(serde_json::json!({
+        "name": "Jace, Test Subject",
+        "manaCost": "{2}{U}{U}",
+        "text": text,
+        "type_line": "Legendary Planeswalker — Jace",
+        "loyalty": "4"
+    }))
+    .unwrap();
+    let abilities = jace.loyalty_abilities();
+    let costs = abilities
+        .iter()
+        .map(|ability| ability.cost)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        costs,
+        [
+            LoyaltyCost::Plus(1),
+            LoyaltyCost::MinusX,
+            LoyaltyCost::Zero,
+            LoyaltyCost::Minus(8)
+        ]
+    );
+    assert_eq!(abilities[2].effect, "Draw a card.");
+    assert_eq!(abilities[1].cost.change(3), -3);
+    assert_eq!(jace.static_abilities(), ["Jace can be your commander."]);
+
+    // every line prints back as it was written
+    let mut lines = abilities
+        .iter()
+        .map(|ability| ability.to_string())
+        .collect::<Vec<_>>();
+    lines.extend(jace.static_abilities());
+    assert_eq!(lines.join("\n"), text);
+    let again: CardData = serde_json::from_value(serde_json::to_value(&jace).unwrap()).unwrap();
+    assert_eq!(again.loyalty_abilities(), abilities);
+
+    // the abilities follow the text when it changes
+    let mut edited = jace.clone();
+    edited.text = Some("-2: Scry 2.\n+X: You gain X life.".to_owned());
+    let abilities = edited.loyalty_abilities();
+    // hand typed text uses a hyphen
+    assert_eq!(abilities[0].to_string(), "−2: Scry 2.");
+    assert_eq!(abilities[1].cost, LoyaltyCost::PlusX);
+    assert!(edited.static_abilities().is_empty());
+    assert!("+: Oops.".parse::<LoyaltyCost>().is_err());
+
+    // only planeswalkers have loyalty abilities
+    let mut sorcery = jace.clone();
+    sorcery.type_data = "Sorcery".parse::<super::TypeLine>().unwrap().type_data;
+    assert!(sorcery.loyalty_abilities().is_empty());
+}
+
+#[test]
+fn keeps_the_modes_of_modal_loyalty_abilities() {
+    use super::types::LoyaltyCost;
+    let text = "+1: Create a 1/1 white Soldier creature token.\n−2: Choose one —\n• Destroy target artifact.\n• Destroy target enchantment.\nIf this planeswalker would be dealt damage, prevent it.";
+    let walker: CardData = serde_json::from_value(serde_json::json!({
+        "name": "Modal Walker",
+        "text": text,
+        "type_line": "Planeswalker — Ajani",
+        "loyalty": "3"
+    }))
+    .unwrap();
+    let abilities = walker.loyalty_abilities();
+    assert_eq!(abilities.len(), 2);
+    assert_eq!(abilities[1].cost, LoyaltyCost::Minus(2));
+    assert_eq!(
+        abilities[1].effect,
+        "Choose one —\n• Destroy target artifact.\n• Destroy target enchantment."
+    );
+    assert_eq!(
+        walker.static_abilities(),
+        ["If this planeswalker would be dealt damage, prevent it."]
+    );
+    let mut lines = abilities
+        .iter()
+        .map(|ability| ability.to_string())
+        .collect::<Vec<_>>();
+    lines.extend(walker.static_abilities());
+    assert_eq!(lines.join("\n"), text);
+}
+
+#[test]
+fn reads_unusual_power_and_toughness() {
+    use super::types::StatValue;
+    use super::CardTypeData;
+    let card = |power: &str, toughness: &str| {
+        serde_json::json!({
+            "name": "Test Subject",
+            "manaCost": "{2}{G}",
+            "supertypes": [],
+            "types": ["Creature"],
+            "subtypes": ["Lhurgoyf"],
+            "power": power,
+            "toughness": toughness
+        })
+    };
+    for (power, toughness) in [("*", "1+*"), ("*²", "*²"), (".5", "3.5"), ("∞", "?")] {
+        let json = card(power, toughness);
+        let data: CardData = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&data).unwrap(), json);
+    }
+    let tarmogoyf: CardData = serde_json::from_value(card("*", "1+*")).unwrap();
+    match &tarmogoyf.type_data[..] {
+        [CardTypeData::Creature(creature)] => {
+            assert_eq!(creature.power.evaluate(3, 0), Some(StatValue::Number(3)));
+            assert_eq!(
+                creature.toughness.evaluate(3, 0),
+                Some(StatValue::Number(4))
+            );
+        }
+        other => panic!("expected creature data, got {:?}", other),
+    }
+    let error = serde_json::from_value::<CardData>(card("two", "2")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "\"two\" is not a power, toughness or loyalty"
+    );
+}
@@ -0,0 +1,296 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::types::{
+    ArtifactData, ArtifactSubtype, BattleData, BattleType, CreatureData, CreatureType,
+    EnchantmentData, EnchantmentType, InstantData, KindredData, LandData, LandType,
+    PlaneswalkerData, PlaneswalkerType, SorceryData, SpellType, SubtypeKind, SubtypeRegistry,
+};
+use super::{CardSupertype, CardType, CardTypeData};
+
+// The order card types are printed in, e.g. "Enchantment Artifact", "Artifact Creature"
+static TYPE_ORDER: [CardType; 9] = [
+    CardType::Kindred,
+    CardType::Enchantment,
+    CardType::Artifact,
+    CardType::Land,
+    CardType::Planeswalker,
+    CardType::Creature,
+    CardType::Battle,
+    CardType::Instant,
+    CardType::Sorcery,
+];
+
+// Every subtype `parse` accepts, marking it as used by some type
+fn claim_subtypes<T>(
+    subtypes: &[impl AsRef<str>],
+    used: &mut [bool],
+    parse: impl Fn(&str) -> Option<T>,
+) -> Vec<T> {
+    subtypes
+        .iter()
+        .zip(used.iter_mut())
+        .flat_map(|(subtype, used)| {
+            let subtype = parse(subtype.as_ref())?;
+            *used = true;
+            Some(subtype)
+        })
+        .collect()
+}
+
+// Type data for each type with the subtypes that belong to it. Anything
+// that isn't power, toughness, etc. is left at its default.
+pub(crate) fn type_data_with_subtypes(
+    registry: &SubtypeRegistry,
+    types: &[CardType],
+    subtypes: &[impl AsRef<str>],
+) -> Result<Vec<CardTypeData>, String> {
+    let mut used = vec![false; subtypes.len()];
+    macro_rules! claim {
+        ($getter:expr) => {
+            claim_subtypes(subtypes, &mut used, $getter)
+        };
+    }
+    let type_data = types
+        .iter()
+        .map(|card_type| match card_type {
+            CardType::Land => CardTypeData::Land(LandData {
+                land_types: claim!(|s| LandType::new_in(registry, s)),
+            }),
+            CardType::Creature => CardTypeData::Creature(CreatureData {
+                creature_types: claim!(|s| CreatureType::new_in(registry, s)),
+                ..Default::default()
+            }),
+            CardType::Artifact => CardTypeData::Artifact(ArtifactData {
+                artifact_types: claim!(|s| ArtifactSubtype::new_in(registry, s)),
+            }),
+            CardType::Enchantment => CardTypeData::Enchantment(EnchantmentData {
+                enchantment_types: claim!(|s| EnchantmentType::new_in(registry, s)),
+            }),
+            CardType::Planeswalker => CardTypeData::Planeswalker(PlaneswalkerData {
+                planeswalker_types: claim!(|s| PlaneswalkerType::new_in(registry, s)),
+                ..Default::default()
+            }),
+            CardType::Kindred => CardTypeData::Kindred(KindredData {
+                kindred_types: claim!(|s| CreatureType::new_in(registry, s)),
+            }),
+            CardType::Instant => CardTypeData::Instant(InstantData {
+                spell_types: claim!(|s| SpellType::new_in(registry, s)),
+            }),
+            CardType::Sorcery => CardTypeData::Sorcery(SorceryData {
+                spell_types: claim!(|s| SpellType::new_in(registry, s)),
+            }),
+            CardType::Battle => CardTypeData::Battle(BattleData {
+                battle_types: claim!(|s| BattleType::new_in(registry, s)),
+                ..Default::default()
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    for data in &type_data {
+        if let CardTypeData::Kindred(kindred) = data {
+            kindred.validate()?;
+        }
+    }
+    let unused_subtypes = subtypes
+        .iter()
+        .zip(used)
+        .filter(|(_, used)| !used)
+        .map(|(subtype, _)| subtype.as_ref())
+        .collect::<Vec<_>>();
+    if !unused_subtypes.is_empty() {
+        return Err(format!("Invalid subtypes: {:?}", unused_subtypes));
+    }
+    Ok(type_data)
+}
+
+impl CardTypeData {
+    pub fn subtypes(&self) -> Vec<String> {
+        fn names<T: Display>(subtypes: &[T]) -> Vec<String> {
+            subtypes.iter().map(|s| s.to_string()).collect()
+        }
+        match self {
+            CardTypeData::Land(data) => names(&data.land_types),
+            CardTypeData::Creature(data) => names(&data.creature_types),
+            CardTypeData::Instant(data) => names(&data.spell_types),
+            CardTypeData::Sorcery(data) => names(&data.spell_types),
+            CardTypeData::Artifact(data) => names(&data.artifact_types),
+            CardTypeData::Enchantment(data) => names(&data.enchantment_types),
+            CardTypeData::Kindred(data) => names(&data.kindred_types),
+            CardTypeData::Planeswalker(data) => names(&data.planeswalker_types),
+            CardTypeData::Battle(data) => names(&data.battle_types),
+        }
+    }
+}
+
+pub(crate) fn format_type_line(supertypes: &[CardSupertype], type_data: &[CardTypeData]) -> String {
+    let mut supertypes = supertypes.to_vec();
+    supertypes.sort();
+    let mut type_data = type_data.iter().collect::<Vec<_>>();
+    type_data.sort_by_key(|data| {
+        TYPE_ORDER
+            .iter()
+            .position(|card_type| *card_type == CardType::from(*data))
+    });
+
+    let mut words = supertypes
+        .iter()
+        .map(|supertype| supertype.to_string())
+        .collect::<Vec<_>>();
+    words.extend(
+        type_data
+            .iter()
+            .map(|data| CardType::from(*data).to_string()),
+    );
+    // a subtype can belong to more than one type, it's still only printed once
+    let mut subtypes: Vec<String> = vec![];
+    for subtype in type_data.iter().flat_map(|data| data.subtypes()) {
+        if !subtypes.contains(&subtype) {
+            subtypes.push(subtype);
+        }
+    }
+    if subtypes.is_empty() {
+        words.join(" ")
+    } else {
+        format!("{} — {}", words.join(" "), subtypes.join(" "))
+    }
+}
+
+// A printed type line: supertypes, card types and subtypes @CR 205
+#[derive(Debug, Clone)]
+pub struct TypeLine {
+    pub supertypes: Vec<CardSupertype>,
+    pub type_data: Vec<CardTypeData>,
+}
+
+// The subtypes after the dash. Some subtypes are more than one word, like
+// "Time Lord", so at each word the longest subtype any of the types has wins.
+fn split_subtypes(registry: &SubtypeRegistry, types: &[CardType], subtypes: &str) -> Vec<String> {
+    let words = subtypes.split_whitespace().collect::<Vec<_>>();
+    let is_subtype = |phrase: &str| {
+        types
+            .iter()
+            .any(|card_type| registry.contains(SubtypeKind::of(*card_type), phrase))
+    };
+    let mut split = vec![];
+    let mut start = 0;
+    while start < words.len() {
+        let end = (start + 2..=words.len())
+            .rev()
+            .find(|end| is_subtype(&words[start..*end].join(" ")))
+            .unwrap_or(start + 1);
+        split.push(words[start..end].join(" "));
+        start = end;
+    }
+    split
+}
+
+impl TypeLine {
+    pub fn new_in(registry: &SubtypeRegistry, s: &str) -> Result<Self, String> {
+        // people typing cards in tend to use a hyphen instead of the dash
+        let (types, subtypes) = s
+            .split_once('—')
+            .or_else(|| s.split_once(" - "))
+            .unwrap_or((s, ""));
+        let mut supertypes = vec![];
+        let mut card_types = vec![];
+        for word in types.split_whitespace() {
+            if let Ok(supertype) = CardSupertype::from_str(word) {
+                supertypes.push(supertype);
+            } else if let Ok(card_type) = CardType::from_str(word) {
+                card_types.push(card_type);
+            } else {
+                return Err(format!("{} is not a supertype or card type", word));
+            }
+        }
+        if card_types.is_empty() {
+            return Err(format!("\"{}\" has no card types", s.trim()));
+        }
+        let subtypes = split_subtypes(registry, &card_types, subtypes);
+        Ok(Self {
+            supertypes,
+            type_data: type_data_with_subtypes(registry, &card_types, &subtypes)?,
+        })
+    }
+}
+
+impl FromStr for TypeLine {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new_in(&SubtypeRegistry::current(), s)
+    }
+}
+
+impl Display for TypeLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_type_line(&self.supertypes, &self.type_data))
+    }
+}
+
+#[test]
+fn parses_type_lines() {
+    let round_trip = |s: &str| TypeLine::from_str(s).unwrap().to_string();
+    for line in [
+        "Legendary Artifact Creature — Equipment Rabbit",
+        "Land Creature — Forest Dryad",
+        "Basic Snow Land — Forest",
+        "Kindred Instant — Shapeshifter",
+        "Legendary Enchantment Artifact",
+        "Legendary Planeswalker — Jace",
+        "Battle — Siege",
+        "Land — Desert",
+        "Sorcery",
+    ] {
+        assert_eq!(round_trip(line), line);
+    }
+    // hand typed lines come out in the canonical form
+    assert_eq!(
+        round_trip("Creature Artifact Legendary - Equipment Rabbit"),
+        "Legendary Artifact Creature — Equipment Rabbit"
+    );
+
+    let bear = TypeLine::from_str("Creature — Bear").unwrap();
+    match &bear.type_data[..] {
+        [CardTypeData::Creature(data)] => assert_eq!(data.creature_types[0].to_string(), "Bear"),
+        other => panic!("expected creature data, got {:?}", other),
+    }
+    assert_eq!(
+        TypeLine::from_str("Creature — Forest").unwrap_err(),
+        "Invalid subtypes: [\"Forest\"]"
+    );
+    assert_eq!(
+        TypeLine::from_str("Legendary Bear").unwrap_err(),
+        "Bear is not a supertype or card type"
+    );
+    assert!(TypeLine::from_str("Legendary").is_err());
+    // Kindred used to be Tribal, and has to have a creature type
+    assert_eq!(
+        round_trip("Tribal Enchantment — Faerie"),
+        "Kindred Enchantment — Faerie"
+    );
+    assert_eq!(
+        TypeLine::from_str("Kindred Sorcery").unwrap_err(),
+        "Kindred cards need at least one creature type"
+    );
+}
+
+#[test]
+fn parses_multi_word_subtypes() {
+    let registry = SubtypeRegistry::from_comprehensive_rules(
+        "205.3m Creatures and kindreds share their lists of subtypes; these subtypes are called creature types. The creature types are Doctor, Time Lord, and Time.\n\
+         205.3q Battles have their own unique set of subtypes; these subtypes are called battle types. The battle types are Siege and Town Hall.",
+    )
+    .unwrap();
+    let doctor = TypeLine::new_in(&registry, "Legendary Creature — Time Lord Doctor").unwrap();
+    assert_eq!(doctor.type_data[0].subtypes(), ["Time Lord", "Doctor"]);
+    assert_eq!(doctor.to_string(), "Legendary Creature — Time Lord Doctor");
+    // a shorter subtype still matches when the longer one doesn't follow
+    let time = TypeLine::new_in(&registry, "Creature — Time Doctor").unwrap();
+    assert_eq!(time.type_data[0].subtypes(), ["Time", "Doctor"]);
+    let town_hall = TypeLine::new_in(&registry, "Battle — Town Hall").unwrap();
+    assert_eq!(town_hall.type_data[0].subtypes(), ["Town Hall"]);
+    assert_eq!(
+        TypeLine::new_in(&registry, "Creature — Time Lord Hall").unwrap_err(),
+        "Invalid subtypes: [\"Hall\"]"
+    );
+}
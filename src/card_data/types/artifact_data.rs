@@ -1,22 +1,14 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
-pub enum ArtifactSubtype {
-    Equipment,
-    Fortification,
-    Blood,
-    Clue,
-    Food,
-    Gold,
-    Incubator,
-    Map,
-    Treasure,
-    Powerstone,
-    Vehicle,
-}
+use super::subtype_registry::registry_subtype;
+
+registry_subtype!(ArtifactSubtype, Artifact, "an artifact type");
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ArtifactData {
-    #[serde(skip)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub artifact_types: Vec<ArtifactSubtype>,
 }
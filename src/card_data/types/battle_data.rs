@@ -1,12 +1,80 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
-pub enum BattleType {
-    Siege,
-}
+use super::subtype_registry::registry_subtype;
+
+use super::MaybePlaceholderNumber;
+
+registry_subtype!(BattleType, Battle, "a battle type");
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct BattleData {
-    #[serde(skip)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub battle_types: Vec<BattleType>,
+    // a battle enters with this many defense counters @CR 310.4
+    #[serde_as(as = "DisplayFromStr")]
+    pub defense: MaybePlaceholderNumber,
+}
+
+impl BattleData {
+    pub fn is_siege(&self) -> bool {
+        self.battle_types
+            .iter()
+            .any(|battle_type| battle_type.as_str() == "Siege")
+    }
+
+    // The players the controller may choose as a Siege's protector, in turn
+    // order: its opponents @CR 310.11a. A Siege with no opponent to protect it
+    // is put into the graveyard @CR 704.5w. None for other battle types, which
+    // the rules don't define yet.
+    pub fn protector_candidates<P: PartialEq + Clone>(
+        &self,
+        controller: &P,
+        players: &[P],
+    ) -> Option<Vec<P>> {
+        self.is_siege().then(|| {
+            players
+                .iter()
+                .filter(|player| *player != controller)
+                .cloned()
+                .collect()
+        })
+    }
+
+    // Neither a battle's protector nor their teammates can attack it @CR 508.1b.
+    // `attacking_team` is the attacking player and their teammates. The
+    // protector is chosen in the game, so it isn't part of the card's data.
+    pub fn can_be_attacked_by<P: PartialEq>(protector: &P, attacking_team: &[P]) -> bool {
+        !attacking_team.contains(protector)
+    }
+
+    // A defeated Siege is exiled and its controller may cast its back face @CR 310.11b
+    pub fn casts_back_face_when_defeated(&self) -> bool {
+        self.is_siege()
+    }
+}
+
+#[test]
+fn follows_siege_rules() {
+    use super::SubtypeRegistry;
+    let registry = SubtypeRegistry::current();
+    let siege = BattleData {
+        battle_types: vec![BattleType::new_in(&registry, "Siege").unwrap()],
+        defense: "5".parse().unwrap(),
+    };
+    assert!(siege.is_siege());
+    assert_eq!(siege.protector_candidates(&1, &[1, 2, 3]), Some(vec![2, 3]));
+    assert_eq!(siege.protector_candidates(&1, &[1]), Some(vec![]));
+    assert!(BattleData::can_be_attacked_by(&2, &[1]));
+    assert!(!BattleData::can_be_attacked_by(&2, &[2]));
+    // Two-Headed Giant: the protector's teammate can't attack it either
+    assert!(!BattleData::can_be_attacked_by(&2, &[1, 2]));
+    assert!(siege.casts_back_face_when_defeated());
+
+    let battle = BattleData::default();
+    assert!(!battle.is_siege());
+    assert_eq!(battle.protector_candidates(&1, &[1, 2]), None);
+    assert!(!battle.casts_back_face_when_defeated());
 }
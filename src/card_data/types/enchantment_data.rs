@@ -1,23 +1,14 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
-pub enum EnchantmentType {
-    Aura,
-    Background,
-    Cartouche,
-    Case,
-    Class,
-    Curse,
-    Role,
-    Room,
-    Rune,
-    Saga,
-    Shard,
-    Shrine,
-}
+use super::subtype_registry::registry_subtype;
+
+registry_subtype!(EnchantmentType, Enchantment, "an enchantment type");
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct EnchantmentData {
-    #[serde(skip)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub enchantment_types: Vec<EnchantmentType>,
 }
@@ -1,19 +1,22 @@
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
-pub enum SpellType {
-    Adventure,
-    Arcane,
-    Lesson,
-    Trap,
-}
+use super::subtype_registry::registry_subtype;
+
+registry_subtype!(SpellType, Spell, "a spell type");
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct InstantData {
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub spell_types: Vec<SpellType>,
 }
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct SorceryData {
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub spell_types: Vec<SpellType>,
 }
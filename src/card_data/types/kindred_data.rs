@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::CreatureType;
+
+// Formerly Tribal
+#[serde_as]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KindredData {
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
+    pub kindred_types: Vec<CreatureType>,
+}
+
+impl KindredData {
+    // Kindred exists to give a noncreature card creature types @CR 308.3
+    pub fn validate(&self) -> Result<(), String> {
+        if self.kindred_types.is_empty() {
+            return Err("Kindred cards need at least one creature type".to_owned());
+        }
+        Ok(())
+    }
+}
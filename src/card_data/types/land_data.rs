@@ -1,6 +1,9 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use super::subtype_registry::{registry_subtype, SubtypeRegistry};
 
 #[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
 pub enum BasicLandType {
@@ -11,39 +14,71 @@ pub enum BasicLandType {
     Forest,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display, strum_macros::EnumString)]
-pub enum NonBasicLandType {
-    Cave,
-    Desert,
-    Gate,
-    Lair,
-    Locus,
-    Sphere,
-    Urzas,
-}
+registry_subtype!(NonBasicLandType, Land, "a land type");
 
-#[derive(Debug, Clone, Serialize, Deserialize, strum_macros::Display)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum LandType {
     Basic(BasicLandType),
-    #[strum(default)]
     NonBasic(NonBasicLandType),
 }
 
+impl Display for LandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic(basic) => basic.fmt(f),
+            Self::NonBasic(nonbasic) => nonbasic.fmt(f),
+        }
+    }
+}
+
+impl LandType {
+    // The name is resolved to the registry's spelling first, so "forest" is
+    // the basic land type Forest. The basic land types are fixed by the rules
+    // @CR 305.6, so they're known even to a registry that lacks them.
+    pub fn new_in(registry: &SubtypeRegistry, s: &str) -> Option<Self> {
+        match NonBasicLandType::new_in(registry, s) {
+            Some(land_type) => Some(
+                BasicLandType::from_str(land_type.as_str())
+                    .map(Self::Basic)
+                    .unwrap_or(Self::NonBasic(land_type)),
+            ),
+            None => BasicLandType::from_str(s).ok().map(Self::Basic),
+        }
+    }
+}
+
 impl FromStr for LandType {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(nonbasic) = NonBasicLandType::from_str(s) {
-            Ok(Self::NonBasic(nonbasic))
-        } else if let Ok(basic) = BasicLandType::from_str(s) {
-            Ok(Self::Basic(basic))
-        } else {
-            Err("Invalid land type".to_owned())
-        }
+        Self::new_in(&SubtypeRegistry::current(), s).ok_or(format!("{} is not a land type", s))
     }
 }
 
+#[serde_as]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct LandData {
-    #[serde(skip)]
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
     pub land_types: Vec<LandType>,
 }
+
+#[test]
+fn reads_lower_case_basic_land_types() {
+    use crate::card_data::TypeLine;
+    for name in ["forest", "FOREST", "Forest"] {
+        assert!(matches!(
+            LandType::from_str(name),
+            Ok(LandType::Basic(BasicLandType::Forest))
+        ));
+    }
+    assert!(matches!(
+        LandType::from_str("desert"),
+        Ok(LandType::NonBasic(desert)) if desert.as_str() == "Desert"
+    ));
+    assert_eq!(
+        LandType::from_str("Bear").unwrap_err(),
+        "Bear is not a land type"
+    );
+    let line = TypeLine::from_str("Basic Land — forest").unwrap();
+    assert_eq!(line.to_string(), "Basic Land — Forest");
+}
@@ -1,11 +1,18 @@
+// Every type's data serializes its subtypes as a "subtypes" list of strings,
+// next to any fields specific to that type (power, loyalty...). When several
+// types are combined on one card, CardData merges them into the flat
+// MTGJSON layout: one "types" list, one "subtypes" list, and the remaining
+// fields of each type side by side.
 mod artifact_data;
 mod battle_data;
 mod creature_data;
 mod enchantment_data;
 mod instant_sorcery_data;
+mod kindred_data;
 mod land_data;
 mod planeswalker_data;
-mod tribal_data;
+mod stat_expression;
+mod subtype_registry;
 
 use std::{fmt::Display, str::FromStr};
 
@@ -14,10 +21,17 @@ pub use battle_data::{BattleData, BattleType};
 pub use creature_data::{CreatureData, CreatureType};
 pub use enchantment_data::{EnchantmentData, EnchantmentType};
 pub use instant_sorcery_data::{InstantData, SorceryData, SpellType};
+pub use kindred_data::KindredData;
 pub use land_data::{BasicLandType, LandData, LandType, NonBasicLandType};
-pub use planeswalker_data::{PlaneswalkerData, PlaneswalkerType};
-pub use tribal_data::TribalData;
+pub(crate) use planeswalker_data::abilities;
+pub use planeswalker_data::{LoyaltyAbility, LoyaltyCost, PlaneswalkerData, PlaneswalkerType};
+pub use stat_expression::{StatExpression, StatTerm, StatValue};
+pub use subtype_registry::{SubtypeKind, SubtypeRegistry};
 
+#[deprecated(note = "Tribal was renamed to Kindred")]
+pub type TribalData = KindredData;
+
+// A battle's defense, either a number or * @CR 310.4
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MaybePlaceholderNumber {
     Placeholder,
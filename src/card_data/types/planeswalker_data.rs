@@ -1,35 +1,130 @@
-use std::fmt::Display;
-
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::FromStr;
 
-use super::MaybePlaceholderNumber;
+use super::subtype_registry::registry_subtype;
+use super::StatExpression;
 
-static PLANESWALKER_TYPES: &str = include_str!("planeswalker_types.txt");
+registry_subtype!(PlaneswalkerType, Planeswalker, "a planeswalker type");
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct PlaneswalkerType(pub String);
+#[serde_as]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PlaneswalkerData {
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    #[serde(rename = "subtypes", default)]
+    pub planeswalker_types: Vec<PlaneswalkerType>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub loyalty: StatExpression,
+}
 
-impl PlaneswalkerType {
-    pub fn new_validated(input: &str) -> Option<Self> {
-        if !PLANESWALKER_TYPES.lines().any(|l| l == input) {
-            return None;
+// Splits rules text into abilities, one per line, except that the modes of a
+// modal ability ("• ...") stay with the ability they belong to @CR 700.2
+pub(crate) fn abilities(text: &str) -> Vec<String> {
+    let mut abilities: Vec<String> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match abilities.last_mut() {
+            Some(ability) if line.starts_with('•') => {
+                ability.push('\n');
+                ability.push_str(line);
+            }
+            _ => abilities.push(line.to_owned()),
         }
-        Some(Self(input.to_owned()))
     }
+    abilities
 }
 
-impl Display for PlaneswalkerType {
+// The loyalty cost of an activated ability: "+1", "−3", "0", "+X" or "−X" @CR 606.4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoyaltyCost {
+    Zero,
+    Plus(u32),
+    Minus(u32),
+    PlusX,
+    MinusX,
+}
+
+impl LoyaltyCost {
+    // How much loyalty activating the ability adds, for a chosen X @CR 606.5
+    pub fn change(&self, x: u32) -> i64 {
+        match self {
+            LoyaltyCost::Zero => 0,
+            LoyaltyCost::Plus(n) => i64::from(*n),
+            LoyaltyCost::Minus(n) => -i64::from(*n),
+            LoyaltyCost::PlusX => i64::from(x),
+            LoyaltyCost::MinusX => -i64::from(x),
+        }
+    }
+}
+
+impl Display for LoyaltyCost {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&self.0)
+        // rules text uses a minus sign, not a hyphen
+        match self {
+            LoyaltyCost::Zero => f.write_str("0"),
+            LoyaltyCost::Plus(n) => f.write_fmt(format_args!("+{}", n)),
+            LoyaltyCost::Minus(n) => f.write_fmt(format_args!("−{}", n)),
+            LoyaltyCost::PlusX => f.write_str("+X"),
+            LoyaltyCost::MinusX => f.write_str("−X"),
+        }
     }
 }
 
-#[serde_as]
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
-pub struct PlaneswalkerData {
-    #[serde(skip)]
-    pub planeswalker_types: Vec<PlaneswalkerType>,
-    #[serde_as(as = "DisplayFromStr")]
-    pub loyalty: MaybePlaceholderNumber,
+impl FromStr for LoyaltyCost {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("{} is not a loyalty cost", s);
+        if s == "0" {
+            return Ok(LoyaltyCost::Zero);
+        }
+        let (plus, amount) = if let Some(amount) = s.strip_prefix('+') {
+            (true, amount)
+        } else if let Some(amount) = s.strip_prefix('−').or_else(|| s.strip_prefix('-')) {
+            (false, amount)
+        } else {
+            return Err(error());
+        };
+        if amount == "X" {
+            return Ok(if plus {
+                LoyaltyCost::PlusX
+            } else {
+                LoyaltyCost::MinusX
+            });
+        }
+        if !amount.chars().all(|c| c.is_ascii_digit()) {
+            return Err(error());
+        }
+        let amount = amount.parse().map_err(|_| error())?;
+        Ok(if plus {
+            LoyaltyCost::Plus(amount)
+        } else {
+            LoyaltyCost::Minus(amount)
+        })
+    }
+}
+
+// A loyalty ability, e.g. "+1: Draw a card." @CR 606.3
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoyaltyAbility {
+    pub cost: LoyaltyCost,
+    pub effect: String,
+}
+
+impl Display for LoyaltyAbility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}: {}", self.cost, self.effect))
+    }
+}
+
+impl FromStr for LoyaltyAbility {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cost, effect) = s
+            .split_once(": ")
+            .ok_or_else(|| format!("\"{}\" is not a loyalty ability", s))?;
+        Ok(Self {
+            cost: LoyaltyCost::from_str(cost.trim())?,
+            effect: effect.to_owned(),
+        })
+    }
 }
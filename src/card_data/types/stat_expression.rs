@@ -0,0 +1,251 @@
+use chumsky::prelude::*;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+// The value of a power, toughness or loyalty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatValue {
+    Number(i32),
+    // un-sets print halves: Half(3) is 3½, Half(0) is ½ and Half(-1) is -½
+    Half(i32),
+    Infinity,
+}
+
+impl StatValue {
+    fn halves(self) -> Option<i64> {
+        match self {
+            StatValue::Number(n) => Some(2 * i64::from(n)),
+            StatValue::Half(n) => Some(2 * i64::from(n) + 1),
+            StatValue::Infinity => None,
+        }
+    }
+
+    fn from_halves(halves: i64) -> Option<Self> {
+        let whole = i32::try_from(halves.div_euclid(2)).ok()?;
+        Some(match halves.rem_euclid(2) {
+            0 => StatValue::Number(whole),
+            _ => StatValue::Half(whole),
+        })
+    }
+
+    // infinity stays infinite, which is all Infinity Elemental needs
+    fn combine(self, other: Self, op: impl Fn(i64, i64) -> i64) -> Option<Self> {
+        match (self.halves(), other.halves()) {
+            (Some(a), Some(b)) => Self::from_halves(op(a, b)),
+            _ => Some(StatValue::Infinity),
+        }
+    }
+}
+
+impl Display for StatValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatValue::Number(n) => f.write_fmt(format_args!("{}", n)),
+            // printed the way un-sets print them
+            StatValue::Half(0) => f.write_str(".5"),
+            StatValue::Half(-1) => f.write_str("-.5"),
+            StatValue::Half(n) => f.write_fmt(format_args!("{}.5", n + i32::from(*n < 0))),
+            StatValue::Infinity => f.write_str("∞"),
+        }
+    }
+}
+
+// One part of a printed power, toughness or loyalty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatTerm {
+    // a number, or ∞
+    Number(StatValue),
+    // defined by a characteristic-defining ability @CR 208.2a
+    Star,
+    StarSquared,
+    // chosen as the permanent enters, e.g. Nissa, Steward of Elements' loyalty
+    X,
+    // Loopy Lobster and friends, which never get a value
+    Unknown,
+    Sum(Box<StatTerm>, Box<StatTerm>),
+    Difference(Box<StatTerm>, Box<StatTerm>),
+}
+
+impl StatTerm {
+    // The value of the term when * is `star` and X is `x`, None if it has no
+    // value at all
+    pub fn evaluate(&self, star: i32, x: i32) -> Option<StatValue> {
+        match self {
+            StatTerm::Number(n) => Some(*n),
+            StatTerm::Star => Some(StatValue::Number(star)),
+            StatTerm::StarSquared => star.checked_mul(star).map(StatValue::Number),
+            StatTerm::X => Some(StatValue::Number(x)),
+            StatTerm::Unknown => None,
+            StatTerm::Sum(a, b) => a
+                .evaluate(star, x)?
+                .combine(b.evaluate(star, x)?, |a, b| a + b),
+            StatTerm::Difference(a, b) => a
+                .evaluate(star, x)?
+                .combine(b.evaluate(star, x)?, |a, b| a - b),
+        }
+    }
+
+    pub fn is_variable(&self) -> bool {
+        match self {
+            StatTerm::Star | StatTerm::StarSquared | StatTerm::X => true,
+            StatTerm::Sum(a, b) | StatTerm::Difference(a, b) => a.is_variable() || b.is_variable(),
+            _ => false,
+        }
+    }
+}
+
+fn term_parser() -> impl Parser<char, StatTerm, Error = Simple<char>> {
+    // "1", ".5", "3.5", with a sign on augment cards' "+1" and "-1"
+    let number = one_of("+-")
+        .or_not()
+        .then(text::digits(10).or_not())
+        .then(just(".5").or_not())
+        .try_map(|((sign, whole), half), span: std::ops::Range<usize>| {
+            if whole.is_none() && half.is_none() {
+                return Err(Simple::custom(span, "expected a number"));
+            }
+            let whole: i64 = whole
+                .map_or(Ok(0), |whole: String| whole.parse())
+                .map_err(|_| Simple::custom(span.clone(), "expected a number"))?;
+            let halves = 2 * whole + i64::from(half.is_some());
+            let halves = if sign == Some('-') { -halves } else { halves };
+            StatValue::from_halves(halves)
+                .map(StatTerm::Number)
+                .ok_or_else(|| Simple::custom(span, "expected a number"))
+        });
+    let atom = choice((
+        just("*²").to(StatTerm::StarSquared),
+        just('*').to(StatTerm::Star),
+        just('X').to(StatTerm::X),
+        just('∞').to(StatTerm::Number(StatValue::Infinity)),
+        just('?').to(StatTerm::Unknown),
+        number,
+    ));
+    // the sign of a leading number belongs to it, after that it's an operator
+    atom.clone()
+        .then(one_of("+-").then(atom).repeated())
+        .foldl(|a, (op, b)| match op {
+            '+' => StatTerm::Sum(Box::new(a), Box::new(b)),
+            _ => StatTerm::Difference(Box::new(a), Box::new(b)),
+        })
+}
+
+// A printed power, toughness or loyalty, e.g. "2", "1+*", "*²" or "∞" @CR 208.2.
+// The printed form is kept so it serializes back as it was read.
+#[derive(Debug, Clone)]
+pub struct StatExpression {
+    printed: String,
+    term: StatTerm,
+}
+
+impl StatExpression {
+    pub fn term(&self) -> &StatTerm {
+        &self.term
+    }
+
+    pub fn evaluate(&self, star: i32, x: i32) -> Option<StatValue> {
+        self.term.evaluate(star, x)
+    }
+
+    // The value of a plain whole number, which is what almost every card prints
+    pub fn as_number(&self) -> Option<i32> {
+        match self.term {
+            StatTerm::Number(StatValue::Number(n)) => Some(n),
+            _ => None,
+        }
+    }
+
+    pub fn is_variable(&self) -> bool {
+        self.term.is_variable()
+    }
+}
+
+impl PartialEq for StatExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.printed == other.printed
+    }
+}
+
+impl Eq for StatExpression {}
+
+impl Hash for StatExpression {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.printed.hash(state)
+    }
+}
+
+impl From<i32> for StatExpression {
+    fn from(value: i32) -> Self {
+        Self {
+            printed: value.to_string(),
+            term: StatTerm::Number(StatValue::Number(value)),
+        }
+    }
+}
+
+impl Default for StatExpression {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl Display for StatExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.printed)
+    }
+}
+
+impl FromStr for StatExpression {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let term = term_parser()
+            .then_ignore(end())
+            .parse(s.trim())
+            .map_err(|_| format!("\"{}\" is not a power, toughness or loyalty", s))?;
+        Ok(Self {
+            printed: s.trim().to_owned(),
+            term,
+        })
+    }
+}
+
+#[test]
+fn parses_stat_expressions() {
+    let parse = |s: &str| StatExpression::from_str(s).unwrap();
+    for printed in [
+        "2", "0", "*", "1+*", "*+1", "2-*", "7-*", "*²", "?", "∞", ".5", "3.5", "X", "+2", "-1",
+    ] {
+        assert_eq!(parse(printed).to_string(), printed);
+    }
+    assert_eq!(parse("3").as_number(), Some(3));
+    assert_eq!(parse("-1").as_number(), Some(-1));
+    assert_eq!(parse("3.5").as_number(), None);
+    assert_eq!(parse("1+*").evaluate(4, 0), Some(StatValue::Number(5)));
+    assert_eq!(parse("7-*").evaluate(2, 0), Some(StatValue::Number(5)));
+    assert_eq!(parse("*²").evaluate(3, 0), Some(StatValue::Number(9)));
+    assert_eq!(parse("X").evaluate(3, 5), Some(StatValue::Number(5)));
+    assert_eq!(parse("*+X").evaluate(3, 5), Some(StatValue::Number(8)));
+    assert_eq!(parse(".5").evaluate(0, 0), Some(StatValue::Half(0)));
+    assert_eq!(parse("3.5").evaluate(0, 0), Some(StatValue::Half(3)));
+    assert_eq!(parse("-.5").evaluate(0, 0), Some(StatValue::Half(-1)));
+    assert_eq!(parse(".5+.5").evaluate(0, 0), Some(StatValue::Number(1)));
+    assert_eq!(parse("∞").evaluate(0, 0), Some(StatValue::Infinity));
+    assert_eq!(parse("?").evaluate(3, 0), None);
+    assert_eq!(parse("1+?").evaluate(3, 0), None);
+    assert_eq!(parse("2").term(), &StatTerm::Number(StatValue::Number(2)));
+    for value in ["3", "-1", ".5", "3.5", "-.5", "-3.5", "∞"] {
+        assert_eq!(parse(value).evaluate(0, 0).unwrap().to_string(), value);
+    }
+    assert!(StatExpression::from_str("1.25").is_err());
+    assert!(parse("X").is_variable());
+    assert!(parse("2-*").is_variable());
+    assert!(!parse("2").is_variable());
+    assert_eq!(StatExpression::default(), parse("0"));
+    assert_eq!(
+        StatExpression::from_str("two"),
+        Err("\"two\" is not a power, toughness or loyalty".to_owned())
+    );
+    assert!(StatExpression::from_str("").is_err());
+    assert!(StatExpression::from_str("1+").is_err());
+}
@@ -0,0 +1,464 @@
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use crate::card_data::CardType;
+
+// The subtype lists of rule 205.3, used when nothing else has been loaded
+static COMPILED_SUBTYPES: &str = include_str!("subtypes.txt");
+
+static CURRENT: OnceLock<RwLock<Arc<SubtypeRegistry>>> = OnceLock::new();
+
+// Each list of subtypes in the rules. Some card types share a list @CR 205.3b
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::EnumIter)]
+pub enum SubtypeKind {
+    Artifact,
+    Battle,
+    Creature,
+    Enchantment,
+    Land,
+    Planeswalker,
+    Spell,
+}
+
+impl SubtypeKind {
+    pub fn of(card_type: CardType) -> Self {
+        match card_type {
+            CardType::Artifact => Self::Artifact,
+            CardType::Battle => Self::Battle,
+            CardType::Creature | CardType::Kindred => Self::Creature,
+            CardType::Enchantment => Self::Enchantment,
+            CardType::Land => Self::Land,
+            CardType::Planeswalker => Self::Planeswalker,
+            CardType::Instant | CardType::Sorcery => Self::Spell,
+        }
+    }
+
+    // How the rules and MTGJSON name the list, e.g. "spell" in "The spell types are..."
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "artifact" => Some(Self::Artifact),
+            "battle" => Some(Self::Battle),
+            "creature" | "kindred" | "tribal" => Some(Self::Creature),
+            "enchantment" => Some(Self::Enchantment),
+            "land" => Some(Self::Land),
+            "planeswalker" => Some(Self::Planeswalker),
+            "spell" | "instant" | "sorcery" => Some(Self::Spell),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubtypeRegistry {
+    subtypes: HashMap<SubtypeKind, Subtypes>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Subtypes {
+    names: HashSet<&'static str>,
+    // every spelling we accept: as printed, lower case and plural
+    lookup: HashMap<String, &'static str>,
+}
+
+// Every subtype name we've seen, so each one is allocated once and they can
+// be compared by address
+fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES.get_or_init(Default::default).lock().unwrap();
+    if let Some(interned) = names.get(name) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+// Creature types whose plural isn't just an added s or es, or that don't change
+static IRREGULAR_PLURALS: [(&str, &str); 14] = [
+    ("Child", "Children"),
+    ("Cyclops", "Cyclopes"),
+    ("Dwarf", "Dwarves"),
+    ("Elf", "Elves"),
+    ("Fish", "Fish"),
+    ("Homunculus", "Homunculi"),
+    ("Kithkin", "Kithkin"),
+    ("Kor", "Kor"),
+    ("Merfolk", "Merfolk"),
+    ("Mouse", "Mice"),
+    ("Ox", "Oxen"),
+    ("Sheep", "Sheep"),
+    ("Werewolf", "Werewolves"),
+    ("Wolf", "Wolves"),
+];
+
+// "Goblins", "Sphinxes", "Harpies"
+fn plural(name: &str) -> String {
+    if let Some((_, plural)) = IRREGULAR_PLURALS.iter().find(|(single, _)| *single == name) {
+        return (*plural).to_owned();
+    }
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|end| name.ends_with(end))
+    {
+        return format!("{}es", name);
+    }
+    if let Some(stem) = name.strip_suffix('y') {
+        if !stem.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            return format!("{}ies", stem);
+        }
+    }
+    format!("{}s", name)
+}
+
+impl Subtypes {
+    // Fails if one of the spellings already belongs to another subtype, e.g. a
+    // plural that is also the name of a subtype
+    fn insert(&mut self, name: &'static str, plural: Option<String>) -> Result<(), String> {
+        let spellings = [Some(name.to_owned()), plural].into_iter().flatten();
+        let spellings = spellings
+            .flat_map(|spelling| {
+                let lower = spelling.to_lowercase();
+                [spelling, lower]
+            })
+            .collect::<Vec<_>>();
+        for spelling in &spellings {
+            match self.lookup.get(spelling) {
+                Some(existing) if !std::ptr::eq(*existing, name) => {
+                    return Err(format!(
+                        "\"{}\" would mean both {} and {}",
+                        spelling, existing, name
+                    ))
+                }
+                _ => {}
+            }
+        }
+        self.names.insert(name);
+        for spelling in spellings {
+            self.lookup.insert(spelling, name);
+        }
+        Ok(())
+    }
+
+    fn get(&self, name: &str) -> Option<&'static str> {
+        // most data is spelled right, so only lower case on a miss
+        self.lookup
+            .get(name)
+            .or_else(|| self.lookup.get(&name.to_lowercase()))
+            .copied()
+    }
+}
+
+// "A, B, and C", "A and B" or just "A"
+fn split_list(list: &str) -> impl Iterator<Item = &str> {
+    list.split(", ")
+        .flat_map(|item| item.split(" and "))
+        .map(|item| item.trim_start_matches("and ").trim())
+        .filter(|item| !item.is_empty())
+}
+
+#[derive(Deserialize)]
+struct MtgJsonCardTypes {
+    data: HashMap<String, MtgJsonTypes>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MtgJsonTypes {
+    #[serde(default)]
+    sub_types: Vec<String>,
+}
+
+impl SubtypeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The lists this crate was built with
+    pub fn compiled() -> Self {
+        Self::from_comprehensive_rules(COMPILED_SUBTYPES).expect("compiled subtypes should parse")
+    }
+
+    // Reads every "The <kind> types are ..." list out of the rules text @CR 205.3
+    pub fn from_comprehensive_rules(text: &str) -> Result<Self, String> {
+        let mut registry = Self::new();
+        for line in text.lines() {
+            let mut rest = line;
+            while let Some(index) = rest.find(" types are ") {
+                let (before, after) = (&rest[..index], &rest[index + " types are ".len()..]);
+                let kind = before
+                    .rfind("The ")
+                    .map(|start| &before[start + "The ".len()..]);
+                let end = after.find(". ").unwrap_or(after.len());
+                let list = after[..end].trim_end_matches('.');
+                if let Some(kind) = kind.and_then(SubtypeKind::from_name) {
+                    for subtype in split_list(list) {
+                        registry.insert(kind, subtype)?;
+                    }
+                }
+                rest = &after[end..];
+            }
+        }
+        if registry.subtypes.is_empty() {
+            return Err("No subtype lists found in the rules text".to_owned());
+        }
+        Ok(registry)
+    }
+
+    // Reads MTGJSON's CardTypes.json
+    pub fn from_mtgjson_card_types(json: &str) -> Result<Self, String> {
+        let card_types: MtgJsonCardTypes =
+            serde_json::from_str(json).map_err(|e| format!("Invalid CardTypes.json: {}", e))?;
+        let mut registry = Self::new();
+        for (name, types) in card_types.data {
+            if let Some(kind) = SubtypeKind::from_name(&name) {
+                for subtype in types.sub_types {
+                    registry.insert(kind, &subtype)?;
+                }
+            }
+        }
+        Ok(registry)
+    }
+
+    pub fn insert(&mut self, kind: SubtypeKind, subtype: &str) -> Result<(), String> {
+        // only creature types are written in the plural, e.g. "other Elves"
+        let plural = (kind == SubtypeKind::Creature).then(|| plural(subtype));
+        self.subtypes
+            .entry(kind)
+            .or_default()
+            .insert(intern(subtype), plural)
+    }
+
+    // The subtype as printed, ignoring case and plurals
+    pub fn get(&self, kind: SubtypeKind, subtype: &str) -> Option<&'static str> {
+        self.subtypes.get(&kind)?.get(subtype)
+    }
+
+    pub fn contains(&self, kind: SubtypeKind, subtype: &str) -> bool {
+        self.get(kind, subtype).is_some()
+    }
+
+    // The subtypes of one kind, sorted
+    pub fn subtypes(&self, kind: SubtypeKind) -> Vec<&'static str> {
+        let mut subtypes = self
+            .subtypes
+            .get(&kind)
+            .into_iter()
+            .flat_map(|subtypes| subtypes.names.iter().copied())
+            .collect::<Vec<_>>();
+        subtypes.sort();
+        subtypes
+    }
+
+    fn current_lock() -> &'static RwLock<Arc<Self>> {
+        CURRENT.get_or_init(|| RwLock::new(Arc::new(Self::compiled())))
+    }
+
+    // The registry subtypes are validated against, the compiled one until
+    // another is installed. Hold on to it when parsing many subtypes, so the
+    // lock is only taken once.
+    pub fn current() -> Arc<Self> {
+        Self::current_lock().read().unwrap().clone()
+    }
+
+    pub fn install(self) {
+        *Self::current_lock().write().unwrap() = Arc::new(self);
+    }
+}
+
+// An interned subtype, valid when it's in the current registry. Copies are
+// free and two of them are compared by address.
+macro_rules! registry_subtype {
+    ($name:ident, $kind:ident, $description:literal) => {
+        #[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+        pub struct $name(&'static str);
+
+        impl $name {
+            pub fn new_validated(input: &str) -> Option<Self> {
+                Self::new_in(&$crate::card_data::types::SubtypeRegistry::current(), input)
+            }
+            pub fn new_in(
+                registry: &$crate::card_data::types::SubtypeRegistry,
+                input: &str,
+            ) -> Option<Self> {
+                registry
+                    .get($crate::card_data::types::SubtypeKind::$kind, input)
+                    .map(Self)
+            }
+            pub fn as_str(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                std::ptr::eq(self.0, other.0)
+            }
+        }
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                std::ptr::hash(self.0, state)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new_validated(s).ok_or(format!("{} is not {}", s, $description))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.0)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = std::borrow::Cow::<str>::deserialize(deserializer)?;
+                name.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+pub(crate) use registry_subtype;
+
+#[test]
+fn loads_subtype_registries() {
+    let compiled = SubtypeRegistry::compiled();
+    assert!(compiled.contains(SubtypeKind::Creature, "Bear"));
+    // every land type of 205.3i, including the ones the old enum was missing
+    for land_type in [
+        "Cave",
+        "Cloud",
+        "Desert",
+        "Gate",
+        "Lair",
+        "Locus",
+        "Mine",
+        "Planet",
+        "Power-Plant",
+        "Sphere",
+        "Tower",
+        "Town",
+        "Urza's",
+    ] {
+        assert!(
+            compiled.contains(SubtypeKind::Land, land_type),
+            "{} is missing",
+            land_type
+        );
+    }
+    assert_eq!(
+        <super::LandType as std::str::FromStr>::from_str("Power-Plant")
+            .unwrap()
+            .to_string(),
+        "Power-Plant"
+    );
+    assert!(compiled.contains(SubtypeKind::Spell, "Omen"));
+    assert!(!compiled.contains(SubtypeKind::Creature, "Forest"));
+    assert_eq!(compiled.subtypes(SubtypeKind::Battle), ["Siege"]);
+
+    let rules = SubtypeRegistry::from_comprehensive_rules(
+        "205.3q Battles have their own unique set of subtypes; these subtypes are called battle types. The battle types are Siege and Town Hall.\n\
+         205.3k Instants and sorceries share their lists of subtypes; these subtypes are called spell types. The spell types are Adventure, Arcane, Chorus, Lesson, Omen, and Trap.",
+    )
+    .unwrap();
+    assert_eq!(rules.subtypes(SubtypeKind::Battle), ["Siege", "Town Hall"]);
+    assert_eq!(rules.subtypes(SubtypeKind::Spell).len(), 6);
+    assert!(SubtypeRegistry::from_comprehensive_rules("100.1 These Magic rules apply").is_err());
+
+    let mtgjson = SubtypeRegistry::from_mtgjson_card_types(
+        r#"{"meta": {"version": "5.2.2"}, "data": {
+            "artifact": {"subTypes": ["Attraction", "Contraption"], "superTypes": ["Legendary"]},
+            "instant": {"subTypes": ["Arcane"], "superTypes": []},
+            "sorcery": {"subTypes": ["Omen"], "superTypes": []},
+            "plane": {"subTypes": ["Dominaria"], "superTypes": []}
+        }}"#,
+    )
+    .unwrap();
+    assert_eq!(mtgjson.subtypes(SubtypeKind::Spell), ["Arcane", "Omen"]);
+    assert!(mtgjson.contains(SubtypeKind::Artifact, "Contraption"));
+    assert!(mtgjson.subtypes(SubtypeKind::Creature).is_empty());
+}
+
+#[test]
+fn looks_up_interned_subtypes() {
+    use super::{CreatureType, PlaneswalkerType};
+    use std::str::FromStr;
+    let creature = |s: &str| CreatureType::from_str(s).unwrap();
+    assert_eq!(creature("Elves"), creature("Elf"));
+    assert_eq!(creature("elf").to_string(), "Elf");
+    assert_eq!(creature("GOBLINS").as_str(), "Goblin");
+    assert_eq!(creature("Sphinxes").as_str(), "Sphinx");
+    assert_eq!(creature("Harpies").as_str(), "Harpy");
+    assert_eq!(creature("Merfolk").as_str(), "Merfolk");
+    assert!(std::ptr::eq(
+        creature("Bear").as_str(),
+        creature("bears").as_str()
+    ));
+    assert_eq!(
+        PlaneswalkerType::from_str("jace").map(|jace| jace.to_string()),
+        Ok("Jace".to_owned())
+    );
+    // only creature types have plurals
+    assert!(PlaneswalkerType::from_str("Jaces").is_err());
+    assert_eq!(
+        serde_json::from_str::<CreatureType>("\"Elves\"").unwrap(),
+        creature("Elf")
+    );
+    assert_eq!(
+        serde_json::to_string(&creature("Elves")).unwrap(),
+        "\"Elf\""
+    );
+}
+
+#[test]
+fn validates_against_installed_registry() {
+    use super::ArtifactSubtype;
+    use std::str::FromStr;
+    assert_eq!(
+        ArtifactSubtype::from_str("Gizmo"),
+        Err("Gizmo is not an artifact type".to_owned())
+    );
+    // a superset of the compiled lists, so other tests aren't affected
+    let mut registry = SubtypeRegistry::compiled();
+    registry.insert(SubtypeKind::Artifact, "Gizmo").unwrap();
+    registry.install();
+    assert_eq!(
+        ArtifactSubtype::from_str("Gizmo").map(|gizmo| gizmo.to_string()),
+        Ok("Gizmo".to_owned())
+    );
+}
+
+#[test]
+fn reports_spelling_collisions() {
+    let mut registry = SubtypeRegistry::new();
+    registry.insert(SubtypeKind::Creature, "Elf").unwrap();
+    // the same subtype again is fine
+    registry.insert(SubtypeKind::Creature, "Elf").unwrap();
+    assert_eq!(
+        registry.insert(SubtypeKind::Creature, "Elves"),
+        Err("\"Elves\" would mean both Elf and Elves".to_owned())
+    );
+    assert_eq!(
+        registry.insert(SubtypeKind::Creature, "ELF"),
+        Err("\"elf\" would mean both Elf and ELF".to_owned())
+    );
+    // the failed inserts left nothing behind
+    assert_eq!(registry.subtypes(SubtypeKind::Creature), ["Elf"]);
+    // other kinds have their own spellings
+    registry.insert(SubtypeKind::Land, "Elves").unwrap();
+    assert!(SubtypeRegistry::from_comprehensive_rules(
+        "205.3m The creature types are Ox and Oxen."
+    )
+    .is_err());
+}
@@ -0,0 +1,361 @@
+use crate::mana::{symbol_parser_with, ManaCost, NonManaSymbol, ParseMode, Symbol};
+use chumsky::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// How the parts of a cost other than symbols start @CR 118.1. A comma only
+// separates parts when one of these follows it, so "Sacrifice Gisela, the
+// Broken Blade" is one part.
+static COMPONENT_STARTS: [&str; 14] = [
+    "Collect evidence ",
+    "Discard ",
+    "Exert ",
+    "Exile ",
+    "Mill ",
+    "Pay ",
+    "Put ",
+    "Remove ",
+    "Return ",
+    "Reveal ",
+    "Sacrifice ",
+    "Tap ",
+    "Untap ",
+    "{",
+];
+
+static NUMBER_WORDS: [&str; 11] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostAmount {
+    Number(usize),
+    X,
+}
+
+impl CostAmount {
+    // "a", "two", "X"; the amount has to be the whole word
+    fn from_word(word: &str) -> Option<Self> {
+        match word {
+            "a" | "an" => Some(Self::Number(1)),
+            "X" => Some(Self::X),
+            other => NUMBER_WORDS
+                .iter()
+                .position(|w| *w == other)
+                .or_else(|| other.parse().ok())
+                .map(Self::Number),
+        }
+    }
+
+    // how the amount is written in front of `noun`
+    fn word(&self, noun: &str) -> String {
+        match self {
+            Self::Number(1) if noun.starts_with(['a', 'e', 'i', 'o', 'u']) => "an".to_owned(),
+            Self::Number(1) => "a".to_owned(),
+            &Self::Number(n) => NUMBER_WORDS
+                .get(n)
+                .map_or_else(|| n.to_string(), |w| w.to_string()),
+            Self::X => "X".to_owned(),
+        }
+    }
+}
+
+impl Display for CostAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => f.write_fmt(format_args!("{}", n)),
+            Self::X => f.write_str("X"),
+        }
+    }
+}
+
+// Some number of objects, e.g. "a creature", "two cards" or "Rabbit Battery".
+// The description is kept as printed, including its plural.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostObjects {
+    pub amount: Option<CostAmount>,
+    pub description: String,
+}
+
+impl CostObjects {
+    fn parse(text: &str) -> Self {
+        if let Some((word, rest)) = text.split_once(' ') {
+            if let Some(amount) = CostAmount::from_word(word) {
+                return Self {
+                    amount: Some(amount),
+                    description: rest.to_owned(),
+                };
+            }
+        }
+        Self {
+            amount: None,
+            description: text.to_owned(),
+        }
+    }
+    pub fn count(&self) -> CostAmount {
+        self.amount.unwrap_or(CostAmount::Number(1))
+    }
+}
+
+impl Display for CostObjects {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(amount) = self.amount {
+            f.write_fmt(format_args!("{} ", amount.word(&self.description)))?
+        }
+        f.write_str(&self.description)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CostComponent {
+    Tap,
+    Untap,
+    Energy(usize),
+    PayLife(CostAmount),
+    Sacrifice(CostObjects),
+    Discard(CostObjects),
+    RemoveCounters {
+        amount: CostAmount,
+        counter: String,
+        from: String,
+    },
+    ExileFromGraveyard(CostObjects),
+    // anything we can't model yet, kept as printed
+    Other(String),
+}
+
+impl Display for CostComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CostComponent::Tap => NonManaSymbol::Tap.fmt(f),
+            CostComponent::Untap => NonManaSymbol::Untap.fmt(f),
+            CostComponent::Energy(n) => {
+                f.write_str("Pay ")?;
+                for _ in 0..*n {
+                    NonManaSymbol::Energy.fmt(f)?
+                }
+                Ok(())
+            }
+            CostComponent::PayLife(amount) => f.write_fmt(format_args!("Pay {} life", amount)),
+            CostComponent::Sacrifice(objects) => f.write_fmt(format_args!("Sacrifice {}", objects)),
+            CostComponent::Discard(objects) => f.write_fmt(format_args!("Discard {}", objects)),
+            CostComponent::RemoveCounters {
+                amount,
+                counter,
+                from,
+            } => f.write_fmt(format_args!(
+                "Remove {} {} counter{} from {}",
+                amount.word(counter),
+                counter,
+                if *amount == CostAmount::Number(1) {
+                    ""
+                } else {
+                    "s"
+                },
+                from
+            )),
+            CostComponent::ExileFromGraveyard(objects) => {
+                f.write_fmt(format_args!("Exile {} from your graveyard", objects))
+            }
+            CostComponent::Other(text) => f.write_str(text),
+        }
+    }
+}
+
+// Everything before the colon of an activated ability @CR 602.1a
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ActivationCost {
+    pub mana: Option<ManaCost>,
+    pub components: Vec<CostComponent>,
+}
+
+impl ActivationCost {
+    pub fn has_tap(&self) -> bool {
+        self.components.contains(&CostComponent::Tap)
+    }
+}
+
+// A part of the cost that is nothing but symbols, like "{2}{B}" or "{T}"
+fn parse_symbols(segment: &str, cost: &mut ActivationCost) -> bool {
+    let Ok(symbols) = symbol_parser_with(ParseMode::Strict)
+        .repeated()
+        .at_least(1)
+        .then_ignore(end())
+        .parse(segment)
+    else {
+        return false;
+    };
+    let mut energy = 0;
+    for symbol in symbols {
+        match symbol {
+            Symbol::Mana(mana) => cost.mana.get_or_insert(ManaCost(vec![])).0.push(mana),
+            Symbol::NonMana(NonManaSymbol::Tap) => cost.components.push(CostComponent::Tap),
+            Symbol::NonMana(NonManaSymbol::Untap) => cost.components.push(CostComponent::Untap),
+            Symbol::NonMana(NonManaSymbol::Energy) => energy += 1,
+            Symbol::NonMana(_) => return false,
+        }
+    }
+    if energy > 0 {
+        cost.components.push(CostComponent::Energy(energy));
+    }
+    true
+}
+
+fn parse_component(segment: &str) -> CostComponent {
+    if let Some(rest) = segment.strip_prefix("Pay ") {
+        if let Some(amount) = rest.strip_suffix(" life").and_then(CostAmount::from_word) {
+            return CostComponent::PayLife(amount);
+        }
+        let energy = NonManaSymbol::Energy.to_string();
+        if !rest.is_empty() && rest.replace(&energy, "").is_empty() {
+            return CostComponent::Energy(rest.len() / energy.len());
+        }
+    }
+    if let Some(rest) = segment.strip_prefix("Sacrifice ") {
+        return CostComponent::Sacrifice(CostObjects::parse(rest));
+    }
+    if let Some(rest) = segment.strip_prefix("Discard ") {
+        return CostComponent::Discard(CostObjects::parse(rest));
+    }
+    if let Some(rest) = segment
+        .strip_prefix("Exile ")
+        .and_then(|rest| rest.strip_suffix(" from your graveyard"))
+    {
+        return CostComponent::ExileFromGraveyard(CostObjects::parse(rest));
+    }
+    if let Some((counters, from)) = segment
+        .strip_prefix("Remove ")
+        .and_then(|rest| rest.split_once(" from "))
+    {
+        if let Some((word, counter)) = counters.split_once(' ') {
+            let counter = counter
+                .strip_suffix(" counters")
+                .or_else(|| counter.strip_suffix(" counter"));
+            if let (Some(amount), Some(counter)) = (CostAmount::from_word(word), counter) {
+                return CostComponent::RemoveCounters {
+                    amount,
+                    counter: counter.to_owned(),
+                    from: from.to_owned(),
+                };
+            }
+        }
+    }
+    CostComponent::Other(segment.to_owned())
+}
+
+impl FromStr for ActivationCost {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments: Vec<String> = vec![];
+        for piece in s.split(',').map(str::trim) {
+            if piece.is_empty() {
+                return Err(format!("Empty cost component in \"{}\"", s));
+            }
+            match segments.last_mut() {
+                Some(segment)
+                    if !COMPONENT_STARTS
+                        .iter()
+                        .any(|start| piece.starts_with(start)) =>
+                {
+                    segment.push_str(", ");
+                    segment.push_str(piece);
+                }
+                _ => segments.push(piece.to_owned()),
+            }
+        }
+        let mut cost = ActivationCost::default();
+        for segment in &segments {
+            if parse_symbols(segment, &mut cost) {
+                continue;
+            }
+            if !COMPONENT_STARTS
+                .iter()
+                .any(|start| segment.starts_with(start))
+            {
+                return Err(format!("\"{}\" is not a cost", segment));
+            }
+            cost.components.push(parse_component(segment));
+        }
+        Ok(cost)
+    }
+}
+
+impl Display for ActivationCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        if let Some(mana) = &self.mana {
+            parts.push(mana.to_string());
+        }
+        parts.extend(self.components.iter().map(|c| c.to_string()));
+        f.write_str(&parts.join(", "))
+    }
+}
+
+// Splits an activated ability line into its cost and the rest of the ability
+pub fn split_activated_ability(line: &str) -> Option<(ActivationCost, &str)> {
+    let (cost, effect) = line.split_once(": ")?;
+    Some((ActivationCost::from_str(cost).ok()?, effect))
+}
+
+#[test]
+fn parses_activation_costs() {
+    let round_trip = |s: &str| ActivationCost::from_str(s).unwrap().to_string();
+    for cost in [
+        "{T}",
+        "{2}{B}, {T}, Sacrifice a creature",
+        "{1}, Discard two cards",
+        "Pay 2 life, Sacrifice an artifact",
+        "{Q}",
+        "Pay {E}{E}",
+        "Remove a +1/+1 counter from Walking Ballista",
+        "{X}, Remove X charge counters from Astral Cornucopia",
+        "Exile a creature card from your graveyard",
+        "Sacrifice Rabbit Battery",
+        "Tap an untapped creature you control",
+    ] {
+        assert_eq!(round_trip(cost), cost);
+    }
+
+    let (cost, effect) =
+        split_activated_ability("{1}, {T}, Sacrifice Mind Stone: Draw a card.").unwrap();
+    assert_eq!(effect, "Draw a card.");
+    assert!(cost.has_tap());
+    assert_eq!(cost.mana.unwrap().to_string(), "{1}");
+    assert_eq!(
+        cost.components[1],
+        CostComponent::Sacrifice(CostObjects {
+            amount: None,
+            description: "Mind Stone".to_owned()
+        })
+    );
+
+    let cost = ActivationCost::from_str("{T}, Remove three time counters from it").unwrap();
+    assert_eq!(
+        cost.components[1],
+        CostComponent::RemoveCounters {
+            amount: CostAmount::Number(3),
+            counter: "time".to_owned(),
+            from: "it".to_owned()
+        }
+    );
+    assert!(ActivationCost::from_str("{T},").is_err());
+
+    // a comma inside a name doesn't start a new part of the cost
+    let cost = ActivationCost::from_str("{T}, Sacrifice Gisela, the Broken Blade").unwrap();
+    assert_eq!(
+        cost.components[1],
+        CostComponent::Sacrifice(CostObjects {
+            amount: None,
+            description: "Gisela, the Broken Blade".to_owned()
+        })
+    );
+    assert_eq!(cost.to_string(), "{T}, Sacrifice Gisela, the Broken Blade");
+
+    // lines that only look like activated abilities
+    assert_eq!(
+        ActivationCost::from_str("Choose one").unwrap_err(),
+        "\"Choose one\" is not a cost"
+    );
+    assert!(split_activated_ability("Kicker—Sacrifice a creature: not a cost.").is_none());
+    assert!(split_activated_ability("Level 1-4: 2/2").is_none());
+}
@@ -1,3 +1,9 @@
+mod activation_cost;
+
+pub use activation_cost::{
+    split_activated_ability, ActivationCost, CostAmount, CostComponent, CostObjects,
+};
+
 pub enum TargetNumber {
     One,
     AnyNumber,
@@ -56,10 +62,9 @@ pub enum Effect {
 }
 
 pub fn test() {
-    let wrath = Effect::OneShot {
+    let _wrath = Effect::OneShot {
         effect_type: OneShotEffectType::Destroy {
             objects: vec![(EffectObject::All, DestroyTargetType::Creature)],
         },
     };
 }
-
@@ -1,5 +1,7 @@
 use crate::card_data::CardData;
 
+// a placeholder until the game state uses it
+#[allow(dead_code)]
 pub struct CardObject<'card> {
     card_data: &'card CardData,
 }
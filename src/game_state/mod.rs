@@ -1 +1,3 @@
 mod game_object;
+mod zone;
+pub use zone::Zone;
@@ -1,7 +1,7 @@
 use chumsky::prelude::*;
 use std::fmt::Display;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, strum_macros::EnumIter)]
 pub enum Color {
     White,
     Blue,
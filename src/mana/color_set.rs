@@ -0,0 +1,256 @@
+use super::color::Color;
+use std::fmt::{Display, Write};
+use std::ops::{BitAnd, BitOr, Sub};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+// A set of colors, stored as one bit per color in WUBRG order
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct ColorSet(u8);
+
+fn bit(color: Color) -> u8 {
+    1 << color as u8
+}
+
+impl ColorSet {
+    pub fn new() -> Self {
+        Self(0)
+    }
+    pub fn all() -> Self {
+        Color::iter().collect()
+    }
+    pub fn insert(&mut self, color: Color) {
+        self.0 |= bit(color)
+    }
+    pub fn remove(&mut self, color: Color) {
+        self.0 &= !bit(color)
+    }
+    pub fn contains(&self, color: Color) -> bool {
+        self.0 & bit(color) != 0
+    }
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+    pub fn is_colorless(&self) -> bool {
+        self.is_empty()
+    }
+    pub fn is_multicolored(&self) -> bool {
+        self.len() > 1
+    }
+    pub fn union(self, other: ColorSet) -> Self {
+        Self(self.0 | other.0)
+    }
+    pub fn intersection(self, other: ColorSet) -> Self {
+        Self(self.0 & other.0)
+    }
+    pub fn difference(self, other: ColorSet) -> Self {
+        Self(self.0 & !other.0)
+    }
+    pub fn is_subset(self, other: ColorSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+    pub fn is_superset(self, other: ColorSet) -> bool {
+        other.is_subset(self)
+    }
+    // Iterates in WUBRG order
+    pub fn iter(&self) -> impl Iterator<Item = Color> {
+        let set = *self;
+        Color::iter().filter(move |c| set.contains(*c))
+    }
+
+    // The order colors are printed in on cards: clockwise around the color
+    // pie, starting wherever keeps the colors closest together
+    pub fn cycle_order(&self) -> Vec<Color> {
+        let colors = self.iter().collect::<Vec<_>>();
+        let distance = |from: Color, to: Color| (to as usize + 5 - from as usize) % 5;
+        let rotated = |start: Color| {
+            let mut rotation = colors.clone();
+            rotation.sort_by_key(|c| distance(start, *c));
+            rotation
+        };
+        // wedges are printed as a color followed by its two enemies
+        if colors.len() == 3 {
+            if let Some(start) = colors.iter().find(|start| {
+                colors
+                    .iter()
+                    .all(|c| [0, 2, 4].contains(&distance(**start, *c)))
+            }) {
+                return rotated(*start);
+            }
+        }
+        colors
+            .iter()
+            .min_by_key(|start| {
+                colors
+                    .iter()
+                    .map(|c| distance(**start, *c))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .map(|start| rotated(*start))
+            .unwrap_or_default()
+    }
+
+    // The common name for this combination of colors. The bits are GRBUW from
+    // the highest to the lowest.
+    pub fn name(&self) -> &'static str {
+        match self.0 {
+            0b00000 => "colorless",
+            0b00001 => "White",
+            0b00010 => "Blue",
+            0b00100 => "Black",
+            0b01000 => "Red",
+            0b10000 => "Green",
+            // guilds
+            0b00011 => "Azorius",
+            0b00110 => "Dimir",
+            0b01100 => "Rakdos",
+            0b11000 => "Gruul",
+            0b10001 => "Selesnya",
+            0b00101 => "Orzhov",
+            0b01010 => "Izzet",
+            0b10100 => "Golgari",
+            0b01001 => "Boros",
+            0b10010 => "Simic",
+            // shards
+            0b10011 => "Bant",
+            0b00111 => "Esper",
+            0b01110 => "Grixis",
+            0b11100 => "Jund",
+            0b11001 => "Naya",
+            // wedges
+            0b10101 => "Abzan",
+            0b01011 => "Jeskai",
+            0b10110 => "Sultai",
+            0b01101 => "Mardu",
+            0b11010 => "Temur",
+            // four colors are named for the one that is missing
+            0b11110 => "four-color sans-W",
+            0b11101 => "four-color sans-U",
+            0b11011 => "four-color sans-B",
+            0b10111 => "four-color sans-R",
+            0b01111 => "four-color sans-G",
+            _ => "five-color",
+        }
+    }
+}
+
+impl From<Color> for ColorSet {
+    fn from(color: Color) -> Self {
+        Self(bit(color))
+    }
+}
+
+impl FromIterator<Color> for ColorSet {
+    fn from_iter<T: IntoIterator<Item = Color>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for color in iter {
+            set.insert(color);
+        }
+        set
+    }
+}
+
+impl Extend<Color> for ColorSet {
+    fn extend<T: IntoIterator<Item = Color>>(&mut self, iter: T) {
+        for color in iter {
+            self.insert(color);
+        }
+    }
+}
+
+impl BitOr for ColorSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for ColorSet {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for ColorSet {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+impl Display for ColorSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_char('C');
+        }
+        for color in self.iter() {
+            f.write_fmt(format_args!("{}", color))?
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ColorSet {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "C" {
+            return Ok(Self::new());
+        }
+        s.chars().map(Color::try_from).collect()
+    }
+}
+
+#[test]
+fn color_set_operations() {
+    use Color::*;
+    let azorius: ColorSet = [Blue, White, Blue].into_iter().collect();
+    assert_eq!(azorius.len(), 2);
+    assert_eq!(azorius.to_string(), "WU");
+    assert_eq!(azorius.name(), "Azorius");
+
+    let esper = azorius | ColorSet::from(Black);
+    assert_eq!(esper.name(), "Esper");
+    assert!(azorius.is_subset(esper));
+    assert!(!esper.is_subset(azorius));
+    assert_eq!(esper & ColorSet::from_str("BG").unwrap(), Black.into());
+    assert_eq!((esper - azorius).iter().collect::<Vec<_>>(), vec![Black]);
+
+    assert_eq!(ColorSet::from_str("GBW").unwrap().name(), "Abzan");
+
+    let cycle = |colors: &str| {
+        ColorSet::from_str(colors)
+            .unwrap()
+            .cycle_order()
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<String>()
+    };
+    assert_eq!(cycle("WG"), "GW");
+    assert_eq!(cycle("WB"), "WB");
+    assert_eq!(cycle("WUG"), "GWU");
+    assert_eq!(cycle("WBG"), "WBG");
+    assert_eq!(cycle("URW"), "URW");
+    assert_eq!(cycle("WUBG"), "GWUB");
+    assert_eq!(cycle("WUBRG"), "WUBRG");
+    assert_eq!((ColorSet::all() - White.into()).name(), "four-color sans-W");
+    assert_eq!(ColorSet::all().name(), "five-color");
+    assert_eq!(ColorSet::from_str("RG").unwrap().name(), "Gruul");
+    assert_eq!(
+        ColorSet::from_str("UBRG").unwrap().name(),
+        "four-color sans-W"
+    );
+    // every combination has its own name
+    let mut names = (0..32)
+        .map(|bits| ColorSet(bits).name())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), 32);
+    assert_eq!(ColorSet::new().to_string(), "C");
+    assert!(ColorSet::from_str("WQ").is_err());
+}
@@ -0,0 +1,127 @@
+use super::color::Color;
+use super::mana_cost::ManaCost;
+use super::mana_symbol::ManaSymbol;
+use std::ops::Add;
+
+use ManaSymbol::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CostModifier {
+    // Additional costs and taxes: kicker, Thalia, Guardian of Thraben
+    Increase(ManaCost),
+    // Goblin Electromancer, affinity, improvise
+    ReduceGeneric(usize),
+    // Reductions of one color, e.g. convoke tapping a green creature
+    ReduceColored(Color, usize),
+}
+
+impl Add for ManaCost {
+    type Output = ManaCost;
+    fn add(self, rhs: Self) -> Self::Output {
+        ManaCost(self.0.into_iter().chain(rhs.0).collect()).normalize()
+    }
+}
+
+impl ManaCost {
+    // The total cost after applying increases and then reductions @CR 601.2f
+    pub fn apply_modifiers(&self, modifiers: &[CostModifier]) -> ManaCost {
+        let mut symbols = self.0.clone();
+        for modifier in modifiers {
+            if let CostModifier::Increase(cost) = modifier {
+                symbols.extend(cost.iter().cloned());
+            }
+        }
+
+        let mut generic_reduction = 0;
+        for modifier in modifiers {
+            match *modifier {
+                CostModifier::ReduceGeneric(n) => generic_reduction += n,
+                CostModifier::ReduceColored(color, n) => {
+                    // colored reductions go to symbols of that color first, plain
+                    // colored symbols before ones that could be paid another way
+                    let mut left = n;
+                    for matches in [
+                        |s: &ManaSymbol, c: Color| *s == Colored(c),
+                        |s: &ManaSymbol, c: Color| *s == Phyrexian(c),
+                        |s: &ManaSymbol, c: Color| {
+                            !matches!(s, Colored(_) | Phyrexian(_)) && s.get_colors().contains(c)
+                        },
+                    ] {
+                        while left > 0 {
+                            match symbols.iter().position(|s| matches(s, color)) {
+                                Some(index) => {
+                                    symbols.remove(index);
+                                    left -= 1;
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                    // anything the colored requirement couldn't absorb reduces generic @CR 118.7
+                    generic_reduction += left;
+                }
+                CostModifier::Increase(_) => {}
+            }
+        }
+
+        // generic reductions never touch colored requirements @CR 118.7
+        for symbol in symbols.iter_mut() {
+            if let Generic(n) = symbol {
+                let reduced = generic_reduction.min(*n);
+                *n -= reduced;
+                generic_reduction -= reduced;
+            }
+        }
+        symbols.retain(|symbol| *symbol != Generic(0));
+        if symbols.is_empty() {
+            symbols.push(Generic(0));
+        }
+        ManaCost(symbols).normalize()
+    }
+}
+
+#[test]
+fn applies_cost_modifiers() {
+    use std::str::FromStr;
+    use Color::*;
+    let cost = |s: &str| ManaCost::from_str(s).unwrap();
+    let modified =
+        |s: &str, modifiers: &[CostModifier]| cost(s).apply_modifiers(modifiers).to_string();
+
+    assert_eq!((cost("{1}{G}") + cost("{1}{R}")).to_string(), "{2}{R}{G}");
+    // Thalia, Guardian of Thraben taxing Lightning Bolt
+    assert_eq!(
+        modified("{R}", &[CostModifier::Increase(cost("{1}"))]),
+        "{1}{R}"
+    );
+    // Goblin Electromancer can't reduce colored requirements
+    assert_eq!(modified("{R}", &[CostModifier::ReduceGeneric(1)]), "{R}");
+    assert_eq!(
+        modified("{2}{U}{R}", &[CostModifier::ReduceGeneric(3)]),
+        "{U}{R}"
+    );
+    // a kicked spell with its kicker partly reduced
+    assert_eq!(
+        modified(
+            "{1}{G}",
+            &[
+                CostModifier::Increase(cost("{2}{G}")),
+                CostModifier::ReduceGeneric(2),
+            ]
+        ),
+        "{1}{G}{G}"
+    );
+    // convoking with two green creatures, the extra one pays generic
+    assert_eq!(
+        modified("{3}{G}", &[CostModifier::ReduceColored(Green, 2)]),
+        "{2}"
+    );
+    assert_eq!(
+        modified("{G/W}{G}", &[CostModifier::ReduceColored(Green, 2)]),
+        "{0}"
+    );
+    assert_eq!(
+        modified("{W}", &[CostModifier::ReduceColored(Green, 1)]),
+        "{W}"
+    );
+}
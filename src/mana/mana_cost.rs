@@ -1,29 +1,88 @@
 use super::color::Color;
-use super::mana_symbol::{mana_symbol_parser, ManaSymbol};
+use super::color_set::ColorSet;
+use super::mana_symbol::{mana_symbol_parser_with, ManaSymbol, ParseMode};
+use super::parse_error::ManaCostParseError;
 use chumsky::prelude::*;
 use std::fmt::Display;
 use std::str::FromStr;
 
-use ManaSymbol::*;
-
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ManaCost(pub Vec<ManaSymbol>);
 
 impl ManaCost {
     pub fn iter(&self) -> std::slice::Iter<'_, ManaSymbol> {
         self.0.iter()
     }
-    pub fn get_colors(&self) -> Vec<Color> {
+    pub fn get_colors(&self) -> ColorSet {
         self.iter()
-            .flat_map(|symbol| match symbol {
-                Hybrid(one, two) | PhyrexianHybrid(one, two) => Some(vec![*one, *two]),
-                Colored(c) | HybridColorless(c) | HybridGeneric(c, _) | Phyrexian(c) => {
-                    Some(vec![*c])
+            .map(ManaSymbol::get_colors)
+            .fold(ColorSet::new(), ColorSet::union)
+    }
+
+    // Puts the symbols in printed order: placeholders, generic, hybrid, then
+    // colored in WUBRG-cycle order, merging all generic symbols into one
+    pub fn normalize(&self) -> ManaCost {
+        use ManaSymbol::*;
+        let order = self.get_colors().cycle_order();
+        let position = |c: &Color| order.iter().position(|o| o == c).unwrap_or(0);
+        let ordered_pair = |one: Color, two: Color| {
+            if position(&one) <= position(&two) {
+                (one, two)
+            } else {
+                (two, one)
+            }
+        };
+
+        let mut generic = 0;
+        let mut had_generic = false;
+        let mut symbols = vec![];
+        for symbol in self.iter() {
+            match *symbol {
+                Generic(n) => {
+                    generic += n;
+                    had_generic = true;
                 }
-                Generic(_) | Placeholder(_) | Colorless | Snow => None,
-            })
-            .flatten()
-            .collect()
+                Hybrid(one, two) => {
+                    let (one, two) = ordered_pair(one, two);
+                    symbols.push(Hybrid(one, two))
+                }
+                PhyrexianHybrid(one, two) => {
+                    let (one, two) = ordered_pair(one, two);
+                    symbols.push(PhyrexianHybrid(one, two))
+                }
+                ref other => symbols.push(other.clone()),
+            }
+        }
+        // {0} is only kept when it is the whole cost
+        if generic > 0 || (symbols.is_empty() && had_generic) {
+            symbols.push(Generic(generic));
+        }
+
+        let group = |symbol: &ManaSymbol| match symbol {
+            Placeholder(_) => 0,
+            Generic(_) | HalfGeneric | Infinity => 1,
+            Colorless => 2,
+            Snow | Legendary => 3,
+            HybridGeneric(..) | HybridColorless(..) | Hybrid(..) | PhyrexianHybrid(..)
+            | Phyrexian(..) | PhyrexianColorless => 4,
+            Colored(_) | Half(_) => 5,
+        };
+        symbols.sort_by_key(|symbol| {
+            let colors = match symbol {
+                &Hybrid(one, two) | &PhyrexianHybrid(one, two) => {
+                    vec![position(&one), position(&two)]
+                }
+                Placeholder(ch) => vec![*ch as usize],
+                other => other.get_colors().iter().map(|c| position(&c)).collect(),
+            };
+            (group(symbol), colors)
+        });
+        ManaCost(symbols)
+    }
+
+    // Whether two costs are the same once normalized, so {G}{1} is equivalent to {1}{G}
+    pub fn is_equivalent(&self, other: &ManaCost) -> bool {
+        self.normalize() == other.normalize()
     }
 }
 
@@ -46,21 +105,29 @@ impl TryFrom<Vec<ManaSymbol>> for ManaCost {
     }
 }
 
-impl FromStr for ManaCost {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        mana_symbol_parser()
+impl ManaCost {
+    pub fn parse(s: &str, mode: ParseMode) -> Result<Self, ManaCostParseError> {
+        mana_symbol_parser_with(mode)
             .repeated()
             .at_least(1)
+            .then_ignore(end())
             .map(|symbols| ManaCost::try_from(symbols).unwrap())
             .parse(s)
-            .map_err(|_| "Error parsing mana cost".to_owned())
+            .map_err(|errors| ManaCostParseError::from_simples(s, errors))
+    }
+}
+
+impl FromStr for ManaCost {
+    type Err = ManaCostParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ManaCost::parse(s, ParseMode::Strict)
     }
 }
 
 #[test]
 fn test_mana_costs() {
-    use Color::*;
+    use super::Color::*;
+    use ManaSymbol::*;
     // Ajani, Sleeper Agent
     assert_eq!(
         ManaCost::from_str("{1}{G}{G/W/P}{W}").unwrap(),
@@ -82,3 +149,46 @@ fn test_mana_costs() {
         ])
     );
 }
+
+#[test]
+fn gets_colors() {
+    // Ajani, Sleeper Agent
+    let ajani = ManaCost::from_str("{1}{G}{G/W/P}{W}").unwrap();
+    assert_eq!(ajani.get_colors().to_string(), "WG");
+    assert!(ManaCost::from_str("{3}{C}")
+        .unwrap()
+        .get_colors()
+        .is_colorless());
+}
+
+#[test]
+fn normalizes_mana_costs() {
+    let normalized = |cost: &str| ManaCost::from_str(cost).unwrap().normalize().to_string();
+    assert_eq!(normalized("{G}{1}"), "{1}{G}");
+    assert_eq!(normalized("{G}{2}{X}{U}{1}"), "{X}{3}{G}{U}");
+    assert_eq!(normalized("{W}{W/G}{1}{G}"), "{1}{G/W}{G}{W}");
+    // Siege Rhino
+    assert_eq!(normalized("{G}{B}{W}{1}"), "{1}{W}{B}{G}");
+    assert_eq!(normalized("{0}"), "{0}");
+    assert_eq!(normalized("{0}{R}"), "{R}");
+    assert!(ManaCost::from_str("{G}{1}")
+        .unwrap()
+        .is_equivalent(&ManaCost::from_str("{1}{G}").unwrap()));
+    assert!(!ManaCost::from_str("{G}{G}")
+        .unwrap()
+        .is_equivalent(&ManaCost::from_str("{1}{G}").unwrap()));
+}
+
+#[test]
+fn parses_placeholders_by_mode() {
+    // Ulamog's Crusher-style costs are fine either way
+    assert!(ManaCost::from_str("{X}{Y}{R}").is_ok());
+    assert!(ManaCost::from_str("{Q}{R}").is_err());
+    // Unhinged's Kill Destroy-alikes need the lenient mode
+    assert_eq!(
+        ManaCost::parse("{Q}{R}", ParseMode::Lenient)
+            .unwrap()
+            .to_string(),
+        "{Q}{R}"
+    );
+}
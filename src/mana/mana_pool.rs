@@ -0,0 +1,167 @@
+use super::mana_cost::ManaCost;
+use super::payment::{ManaType, ManaUnit, Payment, SymbolPayment};
+use crate::card_data::CardType;
+use std::collections::HashMap;
+
+// What a restricted unit of mana can be spent on, e.g. "Spend this mana only to cast creature spells"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpendRestriction {
+    Spells,
+    SpellsOfType(CardType),
+    ActivatedAbilities,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpendPurpose<'a> {
+    // the card types of the spell being cast
+    Spell(&'a [CardType]),
+    ActivatedAbility,
+}
+
+impl SpendRestriction {
+    pub fn allows(&self, purpose: &SpendPurpose) -> bool {
+        match (self, purpose) {
+            (SpendRestriction::Spells, SpendPurpose::Spell(_)) => true,
+            (SpendRestriction::SpellsOfType(card_type), SpendPurpose::Spell(types)) => {
+                types.contains(card_type)
+            }
+            (SpendRestriction::ActivatedAbilities, SpendPurpose::ActivatedAbility) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PooledMana {
+    pub unit: ManaUnit,
+    // name of the object that produced this mana
+    pub source: Option<String>,
+    pub restriction: Option<SpendRestriction>,
+}
+
+impl PooledMana {
+    pub fn new(unit: ManaUnit) -> Self {
+        Self {
+            unit,
+            source: None,
+            restriction: None,
+        }
+    }
+    pub fn can_spend_on(&self, purpose: &SpendPurpose) -> bool {
+        self.restriction
+            .is_none_or(|restriction| restriction.allows(purpose))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManaPool(Vec<PooledMana>);
+
+impl ManaPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn iter(&self) -> std::slice::Iter<'_, PooledMana> {
+        self.0.iter()
+    }
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn add(&mut self, mana: PooledMana) {
+        self.0.push(mana)
+    }
+    pub fn add_units(&mut self, unit: ManaUnit, amount: usize) {
+        self.0
+            .extend(std::iter::repeat_n(PooledMana::new(unit), amount))
+    }
+    pub fn amount_of(&self, mana_type: ManaType) -> usize {
+        self.iter()
+            .filter(|mana| mana.unit.mana_type == mana_type)
+            .count()
+    }
+
+    // Mana empties from the pool at the end of each step and phase @CR 500.4
+    pub fn empty(&mut self) -> Vec<PooledMana> {
+        std::mem::take(&mut self.0)
+    }
+
+    // Pays `cost` for `purpose` and removes the mana that was used from the pool.
+    // The returned payment indexes into the returned list of spent mana.
+    pub fn spend(
+        &mut self,
+        cost: &ManaCost,
+        purpose: &SpendPurpose,
+        placeholders: &HashMap<char, usize>,
+        life: usize,
+    ) -> Result<(Payment, Vec<PooledMana>), String> {
+        let eligible = (0..self.len())
+            .filter(|&i| self.0[i].can_spend_on(purpose))
+            .collect::<Vec<_>>();
+        let units = eligible.iter().map(|&i| self.0[i].unit).collect::<Vec<_>>();
+        let payment = cost.find_payment(&units, placeholders, life)?;
+
+        let mut used = payment
+            .mana_used()
+            .iter()
+            .map(|&i| eligible[i])
+            .collect::<Vec<_>>();
+        used.sort_unstable();
+        let spent_index = |pool_index: usize| used.binary_search(&pool_index).unwrap();
+        let payment = Payment(
+            payment
+                .iter()
+                .map(|symbol_payment| match symbol_payment {
+                    SymbolPayment::Mana(indices) => SymbolPayment::Mana(
+                        indices.iter().map(|&i| spent_index(eligible[i])).collect(),
+                    ),
+                    life => life.clone(),
+                })
+                .collect(),
+        );
+
+        let mut spent = vec![];
+        let mut kept = vec![];
+        for (i, mana) in self.0.drain(..).enumerate() {
+            if used.binary_search(&i).is_ok() {
+                spent.push(mana);
+            } else {
+                kept.push(mana);
+            }
+        }
+        self.0 = kept;
+        Ok((payment, spent))
+    }
+}
+
+#[test]
+fn spends_from_pool() {
+    use super::Color::*;
+    use std::str::FromStr;
+    let green = ManaUnit::new(ManaType::Colored(Green));
+    let mut pool = ManaPool::new();
+    pool.add_units(green, 2);
+    pool.add(PooledMana {
+        unit: ManaUnit::new(ManaType::Colorless),
+        source: Some("Eldrazi Temple".to_owned()),
+        restriction: Some(SpendRestriction::SpellsOfType(CardType::Creature)),
+    });
+    assert_eq!(pool.amount_of(ManaType::Colored(Green)), 2);
+
+    // the restricted mana can't help cast an instant
+    let cost = ManaCost::from_str("{1}{G}{G}").unwrap();
+    let instant = SpendPurpose::Spell(&[CardType::Instant]);
+    assert!(pool.spend(&cost, &instant, &HashMap::new(), 0).is_err());
+    assert_eq!(pool.len(), 3);
+
+    let creature = SpendPurpose::Spell(&[CardType::Creature]);
+    let (payment, spent) = pool.spend(&cost, &creature, &HashMap::new(), 0).unwrap();
+    assert!(pool.is_empty());
+    assert_eq!(payment.mana_used().len(), 3);
+    assert_eq!(spent[2].source.as_deref(), Some("Eldrazi Temple"));
+
+    pool.add_units(green, 1);
+    assert_eq!(pool.empty().len(), 1);
+    assert!(pool.is_empty());
+}
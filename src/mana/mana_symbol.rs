@@ -1,13 +1,16 @@
 use super::color::{color_parser, Color};
+use super::color_set::ColorSet;
 use chumsky::prelude::*;
 use std::{
     fmt::{Display, Write},
     str::FromStr,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ManaSymbol {
-    Placeholder(char), // X, Y
+    // X, Y and Z, or anything else un-sets print. A placeholder that's a symbol
+    // of its own, like 'C' or '2', prints as that symbol and reads back as it.
+    Placeholder(char),
     Generic(usize),
     Colored(Color),
     Colorless,
@@ -17,10 +20,29 @@ pub enum ManaSymbol {
     Phyrexian(Color),
     PhyrexianHybrid(Color, Color),
     Snow,
+    // The following only show up on un-set cards
+    HalfGeneric,
+    Half(Color),
+    Infinity,
+    PhyrexianColorless,
+    Legendary,
 }
 
 use ManaSymbol::*;
 
+impl ManaSymbol {
+    pub fn get_colors(&self) -> ColorSet {
+        match self {
+            Hybrid(one, two) | PhyrexianHybrid(one, two) => [*one, *two].into_iter().collect(),
+            Colored(c) | HybridColorless(c) | HybridGeneric(c, _) | Phyrexian(c) | Half(c) => {
+                (*c).into()
+            }
+            Generic(_) | Placeholder(_) | Colorless | Snow | HalfGeneric | Infinity
+            | PhyrexianColorless | Legendary => ColorSet::new(),
+        }
+    }
+}
+
 impl Display for ManaSymbol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_char('{')?;
@@ -30,27 +52,82 @@ impl Display for ManaSymbol {
             Snow => f.write_char('S')?,
             Colored(c) => f.write_str(&c.to_string())?,
             Hybrid(c_one, c_two) => f.write_fmt(format_args!("{}/{}", c_one, c_two))?,
-            HybridColorless(c) => f.write_fmt(format_args!("{}/C", c))?,
-            HybridGeneric(c, num) => f.write_fmt(format_args!("{}/{}", c, num))?,
+            HybridColorless(c) => f.write_fmt(format_args!("C/{}", c))?,
+            HybridGeneric(c, num) => f.write_fmt(format_args!("{}/{}", num, c))?,
             Phyrexian(c) => f.write_fmt(format_args!("{}/P", c))?,
             PhyrexianHybrid(c_one, c_two) => f.write_fmt(format_args!("{}/{}/P", c_one, c_two))?,
             &Placeholder(ch) => f.write_char(ch)?,
+            HalfGeneric => f.write_char('½')?,
+            Half(c) => f.write_fmt(format_args!("H{}", c))?,
+            Infinity => f.write_char('∞')?,
+            PhyrexianColorless => f.write_str("C/P")?,
+            Legendary => f.write_char('L')?,
         };
         f.write_char('}')
     }
 }
 
+// Strict parsing only accepts the placeholders printed on real cards, lenient
+// parsing accepts any character so un-set costs can still be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+static PLACEHOLDERS: &str = "XYZ";
+// characters that are symbols on their own, so can't be placeholders
+static SYMBOL_CHARACTERS: &str = "0123456789WUBRGCSL½∞{}";
+
+impl ParseMode {
+    fn accepts_placeholder(&self, c: char) -> bool {
+        match self {
+            ParseMode::Strict => PLACEHOLDERS.contains(c),
+            ParseMode::Lenient => !SYMBOL_CHARACTERS.contains(c),
+        }
+    }
+}
+
 pub fn mana_symbol_parser() -> impl Parser<char, ManaSymbol, Error = Simple<char>> {
-    let dec = text::int::<_, Simple<char>>(10);
-    choice((
-        dec.try_map(|digits, span| {
-            let num: usize = str::parse(&digits).map_err(|_| {
+    mana_symbol_parser_with(ParseMode::Strict)
+}
+
+pub fn mana_symbol_parser_with(
+    mode: ParseMode,
+) -> impl Parser<char, ManaSymbol, Error = Simple<char>> {
+    let dec = || {
+        text::int::<_, Simple<char>>(10).try_map(|digits, span| {
+            str::parse::<usize>(&digits).map_err(|_| {
                 Simple::expected_input_found(span, "1234567890".chars().map(Some), Some('A'))
-            })?;
-            Ok(ManaSymbol::Generic(num))
-        }),
+            })
+        })
+    };
+    choice((
+        // hybrid symbols with a generic or colorless half are written with that half
+        // first, but older data puts the color first so both orders are accepted
+        dec()
+            .then_ignore(just("/"))
+            .then(color_parser())
+            .map(|(num, color)| ManaSymbol::HybridGeneric(color, num)),
+        color_parser()
+            .then_ignore(just("/"))
+            .then(dec())
+            .map(|(color, num)| ManaSymbol::HybridGeneric(color, num)),
+        dec().map(ManaSymbol::Generic),
+        just("C/P").to(ManaSymbol::PhyrexianColorless),
+        just("C/")
+            .ignore_then(color_parser())
+            .map(ManaSymbol::HybridColorless),
+        color_parser()
+            .then_ignore(just("/C"))
+            .map(ManaSymbol::HybridColorless),
         just("C").to(ManaSymbol::Colorless),
         just("S").to(ManaSymbol::Snow),
+        just("L").to(ManaSymbol::Legendary),
+        just("½").to(ManaSymbol::HalfGeneric),
+        just("∞").to(ManaSymbol::Infinity),
+        just("H").ignore_then(color_parser()).map(ManaSymbol::Half),
         color_parser()
             .then(just("/").ignore_then(color_parser()))
             .then(just("/").ignore_then(just("P")))
@@ -58,22 +135,21 @@ pub fn mana_symbol_parser() -> impl Parser<char, ManaSymbol, Error = Simple<char
         color_parser()
             .then(just("/").ignore_then(just("P")))
             .map(|(color, _)| ManaSymbol::Phyrexian(color)),
-        color_parser()
-            .then(just("/").ignore_then(dec))
-            .try_map(|(left, digits), span| {
-                let num: usize = str::parse(&digits).map_err(|_| {
-                    Simple::expected_input_found(span, "1234567890".chars().map(Some), Some('A'))
-                })?;
-                Ok(ManaSymbol::HybridGeneric(left, num))
-            }),
-        color_parser()
-            .then(just("/").ignore_then(just("C")))
-            .map(|(left, _)| ManaSymbol::HybridColorless(left)),
         color_parser()
             .then(just("/").ignore_then(color_parser()))
             .map(|(left, right)| ManaSymbol::Hybrid(left, right)),
         color_parser().map(ManaSymbol::Colored),
-        any().map(ManaSymbol::Placeholder),
+        any().try_map(move |c, span| {
+            if mode.accepts_placeholder(c) {
+                Ok(ManaSymbol::Placeholder(c))
+            } else {
+                Err(Simple::expected_input_found(
+                    span,
+                    PLACEHOLDERS.chars().map(Some),
+                    Some(c),
+                ))
+            }
+        }),
     ))
     .delimited_by(just("{"), just("}"))
 }
@@ -81,6 +157,129 @@ pub fn mana_symbol_parser() -> impl Parser<char, ManaSymbol, Error = Simple<char
 impl FromStr for ManaSymbol {
     type Err = Vec<Simple<char>>;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        mana_symbol_parser().parse(s)
+        mana_symbol_parser().then_ignore(end()).parse(s)
+    }
+}
+
+#[cfg(test)]
+fn all_symbols() -> Vec<ManaSymbol> {
+    use strum::IntoEnumIterator;
+    let mut symbols = vec![
+        Colorless,
+        Snow,
+        HalfGeneric,
+        Infinity,
+        PhyrexianColorless,
+        Legendary,
+    ];
+    symbols.extend(PLACEHOLDERS.chars().map(Placeholder));
+    symbols.extend((0..=20).chain([100, 1_000_000]).map(Generic));
+    for one in Color::iter() {
+        symbols.extend([
+            Colored(one),
+            HybridColorless(one),
+            Phyrexian(one),
+            Half(one),
+        ]);
+        symbols.extend((0..=10).map(|n| HybridGeneric(one, n)));
+        for two in Color::iter() {
+            symbols.extend([Hybrid(one, two), PhyrexianHybrid(one, two)]);
+        }
+    }
+    symbols
+}
+
+#[test]
+fn symbols_round_trip() {
+    for symbol in all_symbols() {
+        assert_eq!(
+            ManaSymbol::from_str(&symbol.to_string()),
+            Ok(symbol.clone()),
+            "{} did not round trip",
+            symbol
+        );
+    }
+}
+
+#[cfg(test)]
+fn symbol_strategy() -> impl proptest::strategy::Strategy<Value = ManaSymbol> {
+    use proptest::prelude::*;
+    use strum::IntoEnumIterator;
+    let color = || proptest::sample::select(Color::iter().collect::<Vec<_>>());
+    prop_oneof![
+        proptest::sample::select(PLACEHOLDERS.chars().collect::<Vec<_>>()).prop_map(Placeholder),
+        any::<usize>().prop_map(Generic),
+        color().prop_map(Colored),
+        Just(Colorless),
+        (color(), color()).prop_map(|(one, two)| Hybrid(one, two)),
+        color().prop_map(HybridColorless),
+        (color(), any::<usize>()).prop_map(|(color, n)| HybridGeneric(color, n)),
+        color().prop_map(Phyrexian),
+        (color(), color()).prop_map(|(one, two)| PhyrexianHybrid(one, two)),
+        Just(Snow),
+        Just(HalfGeneric),
+        color().prop_map(Half),
+        Just(Infinity),
+        Just(PhyrexianColorless),
+        Just(Legendary),
+    ]
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn generated_symbols_round_trip(symbol in symbol_strategy()) {
+        proptest::prop_assert_eq!(ManaSymbol::from_str(&symbol.to_string()), Ok(symbol));
+    }
+
+    #[test]
+    fn generated_costs_round_trip(symbols in proptest::collection::vec(symbol_strategy(), 0..8)) {
+        let text = symbols.iter().map(ToString::to_string).collect::<String>();
+        let parsed = mana_symbol_parser().repeated().then_ignore(end()).parse(text);
+        proptest::prop_assert_eq!(parsed, Ok(symbols));
+    }
+
+    // any character that isn't a symbol of its own is a placeholder in lenient mode
+    #[test]
+    fn lenient_placeholders_round_trip(c in proptest::char::any()) {
+        let lenient = |s: &str| mana_symbol_parser_with(ParseMode::Lenient).then_ignore(end()).parse(s);
+        let parsed = lenient(&Placeholder(c).to_string());
+        if SYMBOL_CHARACTERS.contains(c) {
+            proptest::prop_assert_ne!(parsed, Ok(Placeholder(c)));
+        } else {
+            proptest::prop_assert_eq!(parsed, Ok(Placeholder(c)));
+        }
+    }
+}
+
+#[test]
+fn parses_scryfall_symbology() {
+    use Color::*;
+    let parsed = |s: &str| ManaSymbol::from_str(s).unwrap();
+    assert_eq!(parsed("{2/W}"), HybridGeneric(White, 2));
+    assert_eq!(parsed("{W/2}"), HybridGeneric(White, 2));
+    assert_eq!(parsed("{C/U}"), HybridColorless(Blue));
+    assert_eq!(parsed("{U/C}"), HybridColorless(Blue));
+    assert_eq!(parsed("{C/P}"), PhyrexianColorless);
+    assert_eq!(parsed("{HR}"), Half(Red));
+    assert_eq!(parsed("{½}"), HalfGeneric);
+    assert_eq!(parsed("{∞}"), Infinity);
+    assert_eq!(parsed("{100}"), Generic(100));
+    assert_eq!(parsed("{1000000}"), Generic(1_000_000));
+    assert_eq!(parsed("{L}"), Legendary);
+    assert_eq!(parsed("{W/U/P}"), PhyrexianHybrid(White, Blue));
+}
+
+#[test]
+fn rejects_malformed_symbols() {
+    for bad in ["{Q}", "{?}", "{ }", "{}", "{GG}", "{W/}", "{T}"] {
+        assert!(ManaSymbol::from_str(bad).is_err(), "{} was accepted", bad);
     }
+    let lenient = |s: &str| mana_symbol_parser_with(ParseMode::Lenient).parse(s);
+    assert_eq!(lenient("{Q}"), Ok(Placeholder('Q')));
+    assert_eq!(lenient("{?}"), Ok(Placeholder('?')));
+    // a placeholder can't be a symbol of its own
+    assert_eq!(lenient("{C}"), Ok(Colorless));
+    assert!(lenient("{½H}").is_err());
+    assert!(lenient("{}").is_err());
 }
@@ -1,37 +1,135 @@
 use super::ManaCost;
-use super::ManaSymbol::*;
+use super::ManaSymbol::{self, *};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ManaValue(usize);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleFacedKind {
+    Modal,
+    Transforming,
+}
+
+fn symbol_value(symbol: &ManaSymbol, placeholder: impl Fn(char) -> usize) -> usize {
+    match symbol {
+        &Generic(i) => i,
+        // The following symbols only ever add 1 to mana value
+        Colored(..) | Colorless | Hybrid(..) | HybridColorless(..) | Phyrexian(..)
+        | PhyrexianHybrid(..) | Snow | PhyrexianColorless | Legendary => 1,
+        // half mana is worth ½, which a whole number mana value rounds down
+        HalfGeneric | Half(_) => 0,
+        Infinity => usize::MAX,
+        &Placeholder(ch) => placeholder(ch),
+        // if we ever have a color/0 symbol (this would be silly, yes)
+        // then the higher of the two possible costs (1) should be used @CR 202.3f
+        &HybridGeneric(_, i) => {
+            if i >= 1 {
+                i
+            } else {
+                1
+            }
+        }
+    }
+}
+
+impl ManaValue {
+    pub fn new(value: usize) -> Self {
+        Self(value)
+    }
+    pub fn value(&self) -> usize {
+        self.0
+    }
+
+    // On the stack X is whatever was chosen for it @CR 202.3e
+    pub fn on_stack(cost: &ManaCost, placeholders: &HashMap<char, usize>) -> Self {
+        Self(
+            cost.iter()
+                .map(|symbol| {
+                    symbol_value(symbol, |ch| placeholders.get(&ch).copied().unwrap_or(0))
+                })
+                .fold(0, usize::saturating_add),
+        )
+    }
+
+    // A split card not on the stack has the combined mana value of its halves @CR 709.4
+    pub fn split<'a>(halves: impl IntoIterator<Item = Option<&'a ManaCost>>) -> Self {
+        halves
+            .into_iter()
+            .map(|cost| cost.map(ManaValue::from).unwrap_or_default())
+            .fold(Self::default(), Add::add)
+    }
+
+    // Only the front face counts unless a modal back face was cast or put onto
+    // the battlefield, and a transformed back face uses the front face's @CR 712.8
+    pub fn double_faced(
+        front: Option<&ManaCost>,
+        back: Option<&ManaCost>,
+        kind: DoubleFacedKind,
+        back_face_up: bool,
+    ) -> Self {
+        let face = match (kind, back_face_up) {
+            (DoubleFacedKind::Modal, true) => back,
+            _ => front,
+        };
+        face.map(ManaValue::from).unwrap_or_default()
+    }
+}
+
 impl From<&ManaCost> for ManaValue {
     fn from(cost: &ManaCost) -> Self {
         // this is a 'default' method used when all you have is a mana cost
         // more specific situations will have their own overrides
-        let value = cost
-            .iter()
-            .map(|symbol| match symbol {
-                &Generic(i) => i,
-                // The following symbols only ever add 1 to mana value
-                Colored(..) | Colorless | Hybrid(..) | HybridColorless(..) | Phyrexian(..)
-                | PhyrexianHybrid(..) | Snow => 1,
-                // X is 0 when determining mana value of a card not on the stack @CR 202.3e
-                Placeholder(_) => 0,
-                // if we ever have a color/0 symbol (this would be silly, yes)
-                // then the higher of the two possible costs (1) should be used @CR 202.3f
-                &HybridGeneric(_, i) => {
-                    if i >= 1 {
-                        i
-                    } else {
-                        1
-                    }
-                }
-            })
-            .sum();
+        // X is 0 when determining mana value of a card not on the stack @CR 202.3e
+        Self(
+            cost.iter()
+                .map(|symbol| symbol_value(symbol, |_| 0))
+                .fold(0, usize::saturating_add),
+        )
+    }
+}
+
+impl From<usize> for ManaValue {
+    fn from(value: usize) -> Self {
         Self(value)
     }
 }
 
+impl From<ManaValue> for usize {
+    fn from(value: ManaValue) -> Self {
+        value.0
+    }
+}
+
+impl Display for ManaValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}", self.0))
+    }
+}
+
+impl Add for ManaValue {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for ManaValue {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = self.0.saturating_add(rhs.0)
+    }
+}
+
+// Mana value can't go below 0
+impl Sub for ManaValue {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
 #[test]
 fn converts_mana_value() {
     use super::Color::*;
@@ -65,3 +163,37 @@ fn converts_mana_value() {
         ManaValue(6)
     );
 }
+
+#[test]
+fn mana_value_in_other_situations() {
+    use std::str::FromStr;
+    let cost = |s: &str| ManaCost::from_str(s).unwrap();
+    // Altered Ego cast with X = 3
+    let ego = cost("{X}{2}{G}{U}");
+    assert_eq!(
+        ManaValue::on_stack(&ego, &HashMap::from([('X', 3)])).value(),
+        7
+    );
+    assert_eq!(ManaValue::on_stack(&ego, &HashMap::new()), ManaValue(4));
+
+    // Fire // Ice
+    let (fire, ice) = (cost("{1}{R}"), cost("{1}{U}"));
+    assert_eq!(ManaValue::split([Some(&fire), Some(&ice)]), ManaValue(4));
+
+    // Valki, God of Lies // Tibalt, Cosmic Impostor
+    let (valki, tibalt) = (cost("{1}{B}"), cost("{5}{B}{R}"));
+    let mdfc =
+        |up| ManaValue::double_faced(Some(&valki), Some(&tibalt), DoubleFacedKind::Modal, up);
+    assert_eq!(mdfc(false), ManaValue(2));
+    assert_eq!(mdfc(true), ManaValue(7));
+    // Delver of Secrets // Insectile Aberration
+    let delver = cost("{U}");
+    assert_eq!(
+        ManaValue::double_faced(Some(&delver), None, DoubleFacedKind::Transforming, true),
+        ManaValue(1)
+    );
+
+    assert_eq!((ManaValue(2) + ManaValue(3)).to_string(), "5");
+    assert_eq!(ManaValue(2) - ManaValue(3), ManaValue(0));
+    assert_eq!(usize::from(ManaValue::new(6)), 6);
+}
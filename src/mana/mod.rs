@@ -1,8 +1,23 @@
 mod color;
+mod color_set;
+mod cost_modifier;
 mod mana_cost;
+mod mana_pool;
 mod mana_symbol;
 mod mana_value;
+mod parse_error;
+mod payment;
+mod payment_options;
+mod symbol;
 
 pub use color::Color;
+pub use color_set::ColorSet;
+pub use cost_modifier::CostModifier;
 pub use mana_cost::ManaCost;
-pub use mana_symbol::ManaSymbol;
+pub use mana_pool::{ManaPool, PooledMana, SpendPurpose, SpendRestriction};
+pub use mana_symbol::{mana_symbol_parser, mana_symbol_parser_with, ManaSymbol, ParseMode};
+pub use mana_value::{DoubleFacedKind, ManaValue};
+pub use parse_error::ManaCostParseError;
+pub use payment::{ManaType, ManaUnit, Payment, SymbolPayment};
+pub use payment_options::PaymentOption;
+pub use symbol::{non_mana_symbol_parser, symbol_parser_with, NonManaSymbol, Symbol};
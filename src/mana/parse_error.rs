@@ -0,0 +1,209 @@
+use chumsky::error::{Simple, SimpleReason};
+use std::fmt::Display;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaCostParseError {
+    // character offsets into the parsed text
+    pub span: Range<usize>,
+    pub expected: Vec<String>,
+    pub found: Option<char>,
+    pub hint: Option<String>,
+}
+
+// The text of the {...} symbol surrounding a character offset, without braces,
+// and whether it has its closing brace
+fn symbol_at(source: &str, offset: usize) -> Option<(String, bool)> {
+    let chars = source.chars().collect::<Vec<_>>();
+    let start = chars[..offset.min(chars.len())]
+        .iter()
+        .rposition(|c| *c == '{')?;
+    let end = chars[start..].iter().position(|c| *c == '}');
+    let text = chars[start + 1..end.map_or(chars.len(), |end| start + end)]
+        .iter()
+        .collect::<String>();
+    let closed = end.is_some() && !text.contains('{');
+    Some((text, closed))
+}
+
+fn hint_for(source: &str, offset: usize) -> Option<String> {
+    if source.trim().is_empty() {
+        return Some("mana costs need at least one symbol, e.g. {0}".to_owned());
+    }
+    if !source.contains('{') {
+        let symbols = source
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| format!("{{{}}}", c))
+            .collect::<String>();
+        return Some(format!(
+            "every symbol needs its own braces, did you mean {}?",
+            symbols
+        ));
+    }
+    let (symbol, closed) = symbol_at(source, offset)?;
+    // a missing brace is the problem whatever the symbol looks like
+    if !closed {
+        return Some("every { needs a matching }".to_owned());
+    }
+    if symbol.chars().any(|c| c.is_ascii_lowercase()) {
+        return Some(format!(
+            "symbols are upper case, did you mean {{{}}}?",
+            symbol.to_ascii_uppercase()
+        ));
+    }
+    if let [c] = symbol.chars().collect::<Vec<_>>()[..] {
+        if c.is_ascii_alphabetic() {
+            return Some(format!(
+                "{{{}}} isn't a mana symbol, placeholders are {{X}}, {{Y}} or {{Z}}",
+                c
+            ));
+        }
+    }
+    // Phyrexian symbols are written with the P last
+    if let Some(colors) = symbol.strip_prefix("P/") {
+        return Some(format!("did you mean {{{}/P}}?", colors));
+    }
+    if symbol.contains(' ') {
+        return Some(format!(
+            "symbols can't contain spaces, did you mean {{{}}}?",
+            symbol.replace(' ', "")
+        ));
+    }
+    None
+}
+
+impl ManaCostParseError {
+    pub fn from_simple(source: &str, error: Simple<char>) -> Self {
+        let mut expected = error
+            .expected()
+            .map(|token| match token {
+                Some(c) => format!("'{}'", c),
+                None => "end of input".to_owned(),
+            })
+            .collect::<Vec<_>>();
+        if let SimpleReason::Custom(message) = error.reason() {
+            expected.push(message.clone());
+        }
+        expected.sort();
+        expected.dedup();
+        Self {
+            hint: hint_for(source, error.span().start),
+            span: error.span(),
+            expected,
+            found: error.found().copied(),
+        }
+    }
+
+    // Keeps the error that got furthest into the input
+    pub fn from_simples(source: &str, errors: Vec<Simple<char>>) -> Self {
+        let error = errors
+            .into_iter()
+            .max_by_key(|error| error.span().start)
+            .unwrap_or_else(|| Simple::custom(0..0, "could not parse mana cost"));
+        Self::from_simple(source, error)
+    }
+
+    // Renders the error pointing at the offending part of `source`
+    pub fn render(&self, source: &str) -> String {
+        let column = self.span.start + 1;
+        let width = self.span.len().max(1);
+        let gutter = " ".repeat(3);
+        let mut output = format!("Error: {}\n", self);
+        output += &format!("{}╭─[mana cost:1:{}]\n", gutter, column);
+        output += &format!("{}│\n", gutter);
+        output += &format!(" 1 │ {}\n", source);
+        output += &format!(
+            "{}│ {}{}\n",
+            gutter,
+            " ".repeat(self.span.start),
+            "^".repeat(width)
+        );
+        output += &format!(
+            "{}│ {}╰── {}\n",
+            gutter,
+            " ".repeat(self.span.start),
+            self.found_text()
+        );
+        if let Some(hint) = &self.hint {
+            output += &format!("{}│\n", gutter);
+            output += &format!("{}│ Help: {}\n", gutter, hint);
+        }
+        output += &format!("{}╯\n", "─".repeat(3));
+        output
+    }
+
+    fn found_text(&self) -> String {
+        match self.found {
+            Some(c) => format!("unexpected '{}'", c),
+            None => "unexpected end of input".to_owned(),
+        }
+    }
+}
+
+impl Display for ManaCostParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.found_text())?;
+        if !self.expected.is_empty() {
+            f.write_fmt(format_args!(", expected {}", self.expected.join(" or ")))?;
+        }
+        if let Some(hint) = &self.hint {
+            f.write_fmt(format_args!(" ({})", hint))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ManaCostParseError {}
+
+#[test]
+fn explains_parse_errors() {
+    use super::ManaCost;
+    use std::str::FromStr;
+    let error = |s: &str| ManaCost::from_str(s).unwrap_err();
+
+    let phyrexian = error("{1}{P/G}");
+    assert_eq!(phyrexian.span, 4..5);
+    assert_eq!(phyrexian.found, Some('P'));
+    assert_eq!(phyrexian.hint.as_deref(), Some("did you mean {G/P}?"));
+    assert_eq!(
+        phyrexian.render("{1}{P/G}"),
+        [
+            "Error: unexpected 'P', expected 'B' or 'G' or 'R' or 'U' or 'W' or 'X' or 'Y' or 'Z' (did you mean {G/P}?)",
+            "   ╭─[mana cost:1:5]",
+            "   │",
+            " 1 │ {1}{P/G}",
+            "   │     ^",
+            "   │     ╰── unexpected 'P'",
+            "   │",
+            "   │ Help: did you mean {G/P}?",
+            "───╯",
+            "",
+        ]
+        .join("\n")
+    );
+
+    assert_eq!(
+        error("2G").hint.as_deref(),
+        Some("every symbol needs its own braces, did you mean {2}{G}?")
+    );
+    let unclosed = error("{1}{G");
+    assert_eq!(unclosed.found, None);
+    assert_eq!(unclosed.hint.as_deref(), Some("every { needs a matching }"));
+    assert_eq!(
+        error("{1}{G{U}").hint.as_deref(),
+        Some("every { needs a matching }")
+    );
+    assert_eq!(
+        error("").hint.as_deref(),
+        Some("mana costs need at least one symbol, e.g. {0}")
+    );
+    assert_eq!(
+        error("{Q}{R}").hint.as_deref(),
+        Some("{Q} isn't a mana symbol, placeholders are {X}, {Y} or {Z}")
+    );
+    assert_eq!(
+        error("{g/w}").hint.as_deref(),
+        Some("symbols are upper case, did you mean {G/W}?")
+    );
+}
@@ -0,0 +1,320 @@
+use super::color::Color;
+use super::mana_cost::ManaCost;
+use super::mana_symbol::ManaSymbol;
+use std::collections::HashMap;
+
+use ManaSymbol::*;
+
+// The six types of mana @CR 106.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManaType {
+    Colored(Color),
+    Colorless,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ManaUnit {
+    pub mana_type: ManaType,
+    // mana produced by a snow source @CR 107.4h
+    pub snow: bool,
+}
+
+impl ManaUnit {
+    pub fn new(mana_type: ManaType) -> Self {
+        Self {
+            mana_type,
+            snow: false,
+        }
+    }
+    pub fn snow(mana_type: ManaType) -> Self {
+        Self {
+            mana_type,
+            snow: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolPayment {
+    // indices into the pool that was paid from
+    Mana(Vec<usize>),
+    Life(usize),
+}
+
+// One entry per symbol of the cost, in the same order as the cost
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payment(pub Vec<SymbolPayment>);
+
+impl Payment {
+    pub fn iter(&self) -> std::slice::Iter<'_, SymbolPayment> {
+        self.0.iter()
+    }
+    pub fn life_paid(&self) -> usize {
+        self.iter()
+            .map(|payment| match payment {
+                SymbolPayment::Life(life) => *life,
+                SymbolPayment::Mana(_) => 0,
+            })
+            .sum()
+    }
+    pub fn mana_used(&self) -> Vec<usize> {
+        self.iter()
+            .flat_map(|payment| match payment {
+                SymbolPayment::Mana(indices) => indices.clone(),
+                SymbolPayment::Life(_) => vec![],
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ManaFilter {
+    Type(ManaType),
+    Snow,
+}
+
+impl ManaFilter {
+    fn accepts(&self, unit: &ManaUnit) -> bool {
+        match self {
+            ManaFilter::Type(mana_type) => unit.mana_type == *mana_type,
+            ManaFilter::Snow => unit.snow,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Requirement {
+    Mana(ManaFilter),
+    Generic(usize),
+    Life(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    Mana(ManaUnit),
+    Generic(usize),
+    Life(usize),
+}
+
+// Every way a single symbol can be paid, most preferable first
+fn requirement_options(
+    symbol: &ManaSymbol,
+    placeholders: &HashMap<char, usize>,
+) -> Result<Vec<Requirement>, String> {
+    let colored = |c: &Color| Requirement::Mana(ManaFilter::Type(ManaType::Colored(*c)));
+    let colorless = Requirement::Mana(ManaFilter::Type(ManaType::Colorless));
+    Ok(match symbol {
+        &Generic(n) => vec![Requirement::Generic(n)],
+        // X is always paid with generic mana once a value is chosen @CR 107.3
+        Placeholder(ch) => vec![Requirement::Generic(
+            *placeholders
+                .get(ch)
+                .ok_or(format!("No value chosen for {{{}}}", ch))?,
+        )],
+        Colored(c) => vec![colored(c)],
+        Colorless => vec![colorless],
+        Snow => vec![Requirement::Mana(ManaFilter::Snow)],
+        Hybrid(one, two) => vec![colored(one), colored(two)],
+        HybridColorless(c) => vec![colored(c), colorless],
+        &HybridGeneric(c, n) => vec![colored(&c), Requirement::Generic(n)],
+        // Phyrexian symbols can be paid with 2 life instead @CR 107.4f
+        Phyrexian(c) => vec![colored(c), Requirement::Life(2)],
+        PhyrexianHybrid(one, two) => vec![colored(one), colored(two), Requirement::Life(2)],
+        PhyrexianColorless => vec![colorless, Requirement::Life(2)],
+        // no permanent makes half a mana, so a whole one has to be spent
+        Half(c) => vec![colored(c)],
+        HalfGeneric => vec![Requirement::Generic(1)],
+        // mana from legendary sources isn't tracked, so any mana will do
+        Legendary => vec![Requirement::Generic(1)],
+        // no pool holds infinite mana, say so instead of failing the search
+        Infinity => {
+            return Err(format!(
+                "{} can't be paid with a finite amount of mana",
+                symbol
+            ))
+        }
+    })
+}
+
+struct Search<'a> {
+    options: &'a [Vec<Requirement>],
+    // distinct units in the pool and how many of each are left
+    available: Vec<(ManaUnit, usize)>,
+    choices: Vec<Choice>,
+}
+
+impl Search<'_> {
+    fn remaining(&self) -> usize {
+        self.available.iter().map(|(_, count)| count).sum()
+    }
+
+    fn run(&mut self, generic: usize, life: usize) -> bool {
+        let index = self.choices.len();
+        let Some(options) = self.options.get(index) else {
+            // generic mana can be paid with anything that is left over
+            return self.remaining() >= generic;
+        };
+        for option in options {
+            match *option {
+                Requirement::Mana(filter) => {
+                    for class in 0..self.available.len() {
+                        let (unit, count) = self.available[class];
+                        if count == 0 || !filter.accepts(&unit) {
+                            continue;
+                        }
+                        self.available[class].1 -= 1;
+                        self.choices.push(Choice::Mana(unit));
+                        if self.run(generic, life) {
+                            return true;
+                        }
+                        self.choices.pop();
+                        self.available[class].1 += 1;
+                    }
+                }
+                Requirement::Generic(n) => {
+                    if self.remaining() < generic + n {
+                        continue;
+                    }
+                    self.choices.push(Choice::Generic(n));
+                    if self.run(generic + n, life) {
+                        return true;
+                    }
+                    self.choices.pop();
+                }
+                Requirement::Life(n) => {
+                    if life < n {
+                        continue;
+                    }
+                    self.choices.push(Choice::Life(n));
+                    if self.run(generic, life - n) {
+                        return true;
+                    }
+                    self.choices.pop();
+                }
+            }
+        }
+        false
+    }
+}
+
+impl ManaCost {
+    // Finds a way to pay this cost out of `pool`, choosing values for any
+    // placeholders from `placeholders` and paying at most `life` life
+    pub fn find_payment(
+        &self,
+        pool: &[ManaUnit],
+        placeholders: &HashMap<char, usize>,
+        life: usize,
+    ) -> Result<Payment, String> {
+        let options = self
+            .iter()
+            .map(|symbol| requirement_options(symbol, placeholders))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut available: Vec<(ManaUnit, usize)> = vec![];
+        for unit in pool {
+            match available.iter_mut().find(|(u, _)| u == unit) {
+                Some((_, count)) => *count += 1,
+                None => available.push((*unit, 1)),
+            }
+        }
+        // hold on to snow mana for as long as possible, it can pay for more
+        available.sort_by_key(|(unit, _)| unit.snow);
+
+        let mut search = Search {
+            options: &options,
+            available,
+            choices: vec![],
+        };
+        if !search.run(0, life) {
+            return Err(format!("Cannot pay {} with the available mana", self));
+        }
+
+        let mut used = vec![false; pool.len()];
+        let mut take = |accepts: &dyn Fn(&ManaUnit) -> bool| {
+            let index = (0..pool.len())
+                .find(|&i| !used[i] && accepts(&pool[i]))
+                .expect("search only succeeds when enough mana is available");
+            used[index] = true;
+            index
+        };
+        // specific mana first so generic costs can't steal it
+        let mut payments: Vec<Option<SymbolPayment>> = search
+            .choices
+            .iter()
+            .map(|choice| match choice {
+                Choice::Mana(unit) => Some(SymbolPayment::Mana(vec![take(&|u| u == unit)])),
+                Choice::Life(n) => Some(SymbolPayment::Life(*n)),
+                Choice::Generic(_) => None,
+            })
+            .collect();
+        for (payment, choice) in payments.iter_mut().zip(search.choices.iter()) {
+            if let Choice::Generic(n) = choice {
+                *payment = Some(SymbolPayment::Mana(
+                    (0..*n).map(|_| take(&|_| true)).collect(),
+                ));
+            }
+        }
+        Ok(Payment(payments.into_iter().flatten().collect()))
+    }
+}
+
+#[test]
+fn finds_payments() {
+    use std::str::FromStr;
+    use Color::*;
+    let green = ManaUnit::new(ManaType::Colored(Green));
+    let white = ManaUnit::new(ManaType::Colored(White));
+    let colorless = ManaUnit::new(ManaType::Colorless);
+    let none = HashMap::new();
+
+    // Ajani, Sleeper Agent
+    let ajani = ManaCost::from_str("{1}{G}{G/W/P}{W}").unwrap();
+    let payment = ajani
+        .find_payment(&[white, green, green, colorless], &none, 0)
+        .unwrap();
+    assert_eq!(
+        payment,
+        Payment(vec![
+            SymbolPayment::Mana(vec![3]),
+            SymbolPayment::Mana(vec![1]),
+            SymbolPayment::Mana(vec![2]),
+            SymbolPayment::Mana(vec![0]),
+        ])
+    );
+    // the hybrid symbol has to fall back to life
+    let payment = ajani
+        .find_payment(&[white, green, colorless], &none, 20)
+        .unwrap();
+    assert_eq!(payment.life_paid(), 2);
+    assert!(ajani
+        .find_payment(&[white, green, colorless], &none, 1)
+        .is_err());
+
+    // Altered Ego needs a value for X
+    let ego = ManaCost::from_str("{X}{2}{G}{U}").unwrap();
+    let blue = ManaUnit::new(ManaType::Colored(Blue));
+    let pool = [green, blue, colorless, colorless, colorless];
+    assert!(ego.find_payment(&pool, &none, 0).is_err());
+    assert!(ego
+        .find_payment(&pool, &HashMap::from([('X', 1)]), 0)
+        .is_ok());
+    assert!(ego
+        .find_payment(&pool, &HashMap::from([('X', 2)]), 0)
+        .is_err());
+
+    // snow mana has to be saved for {S}
+    let snow_green = ManaUnit::snow(ManaType::Colored(Green));
+    let snow_cost = ManaCost(vec![Colored(Green), Snow]);
+    let payment = snow_cost
+        .find_payment(&[snow_green, green], &none, 0)
+        .unwrap();
+    assert_eq!(payment.mana_used(), vec![1, 0]);
+
+    // Mox Lotus can't be paid for, and the error says why
+    let lotus = ManaCost::from_str("{∞}{∞}{∞}").unwrap();
+    assert_eq!(
+        lotus.find_payment(&[green; 20], &none, 20),
+        Err("{∞} can't be paid with a finite amount of mana".to_owned())
+    );
+}
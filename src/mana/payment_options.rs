@@ -0,0 +1,134 @@
+use super::mana_cost::ManaCost;
+use super::mana_symbol::ManaSymbol;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use ManaSymbol::*;
+
+// A cost with every choice already made: no hybrid or Phyrexian symbols remain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentOption {
+    pub cost: ManaCost,
+    pub life: usize,
+}
+
+impl Display for PaymentOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.cost.iter().next(), self.life) {
+            (None, life) => f.write_fmt(format_args!("{} life", life)),
+            (Some(_), 0) => f.write_fmt(format_args!("{}", self.cost)),
+            (Some(_), life) => f.write_fmt(format_args!("{} and {} life", self.cost, life)),
+        }
+    }
+}
+
+// Each way a single symbol can be paid, as (symbol to pay instead, life to pay)
+fn reductions(symbol: &ManaSymbol) -> Vec<(Option<ManaSymbol>, usize)> {
+    match symbol {
+        &Hybrid(one, two) => vec![(Some(Colored(one)), 0), (Some(Colored(two)), 0)],
+        &HybridColorless(c) => vec![(Some(Colored(c)), 0), (Some(Colorless), 0)],
+        &HybridGeneric(c, n) => vec![(Some(Colored(c)), 0), (Some(Generic(n)), 0)],
+        // Phyrexian symbols can be paid with 2 life instead @CR 107.4f
+        &Phyrexian(c) => vec![(Some(Colored(c)), 0), (None, 2)],
+        &PhyrexianHybrid(one, two) => {
+            vec![(Some(Colored(one)), 0), (Some(Colored(two)), 0), (None, 2)]
+        }
+        PhyrexianColorless => vec![(Some(Colorless), 0), (None, 2)],
+        other => vec![(Some(other.clone()), 0)],
+    }
+}
+
+// Walks the choices for each symbol in turn, depth first so the options come
+// out lazily in the order the choices are listed. Partial payments that come to
+// the same cost after the same symbols lead to the same options, so each is
+// only followed once, which keeps long hybrid costs polynomial.
+struct PaymentOptions {
+    symbols: Vec<ManaSymbol>,
+    // (symbols reduced so far, their normalized reduction, life paid)
+    stack: Vec<(usize, ManaCost, usize)>,
+    seen: HashSet<(usize, ManaCost, usize)>,
+}
+
+impl Iterator for PaymentOptions {
+    type Item = PaymentOption;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((done, cost, life)) = self.stack.pop() {
+            let Some(symbol) = self.symbols.get(done) else {
+                return Some(PaymentOption { cost, life });
+            };
+            for (choice, extra_life) in reductions(symbol).into_iter().rev() {
+                let mut symbols = cost.0.clone();
+                symbols.extend(choice);
+                // normalizing collapses reductions that only differ in symbol order
+                let next = (done + 1, ManaCost(symbols).normalize(), life + extra_life);
+                if self.seen.insert(next.clone()) {
+                    self.stack.push(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl ManaCost {
+    // Every distinct concrete cost this cost can be paid as
+    pub fn payment_options(&self) -> impl Iterator<Item = PaymentOption> {
+        PaymentOptions {
+            symbols: self.0.clone(),
+            stack: vec![(0, ManaCost(vec![]), 0)],
+            seen: HashSet::new(),
+        }
+    }
+}
+
+#[test]
+fn enumerates_payment_options() {
+    use super::Color::*;
+    use std::str::FromStr;
+    let options = |cost: &str| {
+        ManaCost::from_str(cost)
+            .unwrap()
+            .payment_options()
+            .map(|option| option.to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(options("{G/W/P}"), vec!["{G}", "{W}", "2 life"]);
+    // Ajani, Sleeper Agent
+    assert_eq!(
+        options("{1}{G}{G/W/P}{W}"),
+        vec!["{1}{G}{G}{W}", "{1}{G}{W}{W}", "{1}{G}{W} and 2 life"]
+    );
+    // {G/W}{W/G} only has three distinct reductions
+    assert_eq!(options("{G/W}{W/G}").len(), 3);
+    // Advice from the Fae
+    assert_eq!(
+        options("{U/2}{U/2}{U/2}"),
+        vec!["{U}{U}{U}", "{2}{U}{U}", "{4}{U}", "{6}"]
+    );
+    // Gitaxian Probe
+    assert_eq!(
+        ManaCost(vec![Phyrexian(Blue)]).payment_options().last(),
+        Some(PaymentOption {
+            cost: ManaCost(vec![]),
+            life: 2
+        })
+    );
+    // each symbol's choices are merged before the next, so this doesn't try
+    // all 3^14 combinations
+    let options = ManaCost::from_str(&"{W/U/P}".repeat(14))
+        .unwrap()
+        .payment_options()
+        .collect::<Vec<_>>();
+    assert_eq!(options.len(), 120);
+    assert_eq!(
+        options[0].to_string(),
+        format!("{{{}}}", "W}{".repeat(13) + "W")
+    );
+    assert_eq!(options.last().unwrap().to_string(), "28 life");
+    // nothing is worked out until it's asked for
+    let mut lazy = ManaCost::from_str(&"{W/U/P}".repeat(40))
+        .unwrap()
+        .payment_options();
+    assert!(lazy.next().is_some());
+}
@@ -0,0 +1,126 @@
+use super::mana_symbol::{mana_symbol_parser_with, ManaSymbol, ParseMode};
+use chumsky::prelude::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Symbols that show up in costs and rules text but don't represent mana
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::EnumIter)]
+pub enum NonManaSymbol {
+    Tap,
+    Untap,
+    Energy,
+    Ticket,
+    Planeswalker,
+    Chaos,
+    // acorn counters on un-set cards
+    Acorn,
+    // stands for any Phyrexian mana symbol in reminder text @CR 107.4f
+    Phyrexian,
+}
+
+impl NonManaSymbol {
+    fn text(&self) -> &'static str {
+        match self {
+            NonManaSymbol::Tap => "T",
+            NonManaSymbol::Untap => "Q",
+            NonManaSymbol::Energy => "E",
+            NonManaSymbol::Ticket => "TK",
+            NonManaSymbol::Planeswalker => "PW",
+            NonManaSymbol::Chaos => "CHAOS",
+            NonManaSymbol::Acorn => "A",
+            NonManaSymbol::Phyrexian => "P",
+        }
+    }
+}
+
+impl Display for NonManaSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{{{}}}", self.text()))
+    }
+}
+
+// Any symbol from Scryfall's symbology list
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    Mana(ManaSymbol),
+    NonMana(NonManaSymbol),
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Symbol::Mana(symbol) => symbol.fmt(f),
+            Symbol::NonMana(symbol) => symbol.fmt(f),
+        }
+    }
+}
+
+pub fn non_mana_symbol_parser() -> impl Parser<char, NonManaSymbol, Error = Simple<char>> {
+    use NonManaSymbol::*;
+    // longer symbols first so {TK} isn't read as {T}
+    choice((
+        just("CHAOS").to(Chaos),
+        just("TK").to(Ticket),
+        just("PW").to(Planeswalker),
+        just("P").to(Phyrexian),
+        just("A").to(Acorn),
+        just("T").to(Tap),
+        just("Q").to(Untap),
+        just("E").to(Energy),
+    ))
+    .delimited_by(just("{"), just("}"))
+}
+
+pub fn symbol_parser_with(mode: ParseMode) -> impl Parser<char, Symbol, Error = Simple<char>> {
+    non_mana_symbol_parser()
+        .map(Symbol::NonMana)
+        .or(mana_symbol_parser_with(mode).map(Symbol::Mana))
+}
+
+impl FromStr for NonManaSymbol {
+    type Err = Vec<Simple<char>>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        non_mana_symbol_parser().then_ignore(end()).parse(s)
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = Vec<Simple<char>>;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        symbol_parser_with(ParseMode::Strict)
+            .then_ignore(end())
+            .parse(s)
+    }
+}
+
+#[test]
+fn parses_all_symbols() {
+    use strum::IntoEnumIterator;
+    for symbol in NonManaSymbol::iter() {
+        assert_eq!(
+            Symbol::from_str(&symbol.to_string()),
+            Ok(Symbol::NonMana(symbol))
+        );
+    }
+    assert_eq!(
+        Symbol::from_str("{X}"),
+        Ok(Symbol::Mana(ManaSymbol::Placeholder('X')))
+    );
+    assert_eq!(
+        Symbol::from_str("{2/W}").map(|s| s.to_string()),
+        Ok("{2/W}".to_owned())
+    );
+    assert_eq!(
+        Symbol::from_str("{A}"),
+        Ok(Symbol::NonMana(NonManaSymbol::Acorn))
+    );
+    assert_eq!(
+        Symbol::from_str("{P}"),
+        Ok(Symbol::NonMana(NonManaSymbol::Phyrexian))
+    );
+    assert_eq!(
+        Symbol::from_str("{PW}"),
+        Ok(Symbol::NonMana(NonManaSymbol::Planeswalker))
+    );
+    assert!(Symbol::from_str("{TAP}").is_err());
+}